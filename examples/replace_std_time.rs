@@ -0,0 +1,27 @@
+//! Moves a program from `std::time` to this crate's clocks by changing
+//! nothing but its imports.
+
+unix_clock::replace_std_time!();
+
+// was `use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};`
+use time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn work() -> u64 {
+    (0..1_000_000u64).fold(0, |acc, i| acc.wrapping_mul(31).wrapping_add(i))
+}
+
+fn main() {
+    let start = Instant::now();
+    let res = work();
+    let elapsed = start.elapsed();
+
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO);
+    println!(
+        "computed {} in {:?}, {}s after the epoch",
+        res,
+        elapsed,
+        since_epoch.as_secs()
+    );
+}
@@ -0,0 +1,25 @@
+#![no_main]
+
+use std::time::Duration;
+
+use libfuzzer_sys::fuzz_target;
+use unix_clock::{raw::Timespec, SystemTime};
+
+fuzz_target!(|input: (SystemTime, Duration)| {
+    let (time, duration) = input;
+
+    let t = Timespec::from(time);
+    assert!(t.nsecs() < 1_000_000_000);
+
+    if let Some(later) = time.checked_add(duration) {
+        assert!(Timespec::from(later).nsecs() < 1_000_000_000);
+        assert_eq!(later.duration_since(time).ok(), Some(duration));
+        assert_eq!(later.checked_sub(duration), Some(time));
+    }
+
+    if let Some(earlier) = time.checked_sub(duration) {
+        assert!(Timespec::from(earlier).nsecs() < 1_000_000_000);
+        assert_eq!(time.duration_since(earlier).ok(), Some(duration));
+        assert_eq!(earlier.checked_add(duration), Some(time));
+    }
+});
@@ -0,0 +1,114 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{raw, Instant};
+
+const NSEC_PER_SEC: u64 = 1_000_000_000;
+
+/// An [`Instant`] which can be shared between threads, e.g. to track the last
+/// activity of a connection updated from several threads.
+///
+/// The instant is stored as nanoseconds since the zero of its clock in an
+/// `AtomicU64`, which holds about 584 years: far more than any uptime, but
+/// instants before the zero of the clock, or later than 584 years after it,
+/// saturate to the closest representable one.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+/// use unix_clock::{AtomicInstant, Instant};
+///
+/// let last_seen = AtomicInstant::new(Instant::now());
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             last_seen.fetch_max(Instant::now(), Ordering::Relaxed);
+///         });
+///     }
+/// });
+/// assert!(last_seen.load(Ordering::Relaxed) <= Instant::now());
+/// ```
+#[derive(Debug)]
+pub struct AtomicInstant {
+    nanos: AtomicU64,
+}
+
+#[inline]
+const fn to_nanos(instant: Instant) -> u64 {
+    let nanos = instant.t.to_nanos_i128();
+    if nanos < 0 {
+        0
+    } else if nanos > u64::MAX as i128 {
+        u64::MAX
+    } else {
+        nanos as u64
+    }
+}
+
+#[inline]
+fn from_nanos(nanos: u64) -> Instant {
+    Instant {
+        t: raw::Timespec::new((nanos / NSEC_PER_SEC) as i64, (nanos % NSEC_PER_SEC) as u32),
+    }
+}
+
+impl AtomicInstant {
+    /// Creates an atomic instant holding `instant`.
+    #[inline]
+    pub const fn new(instant: Instant) -> Self {
+        Self {
+            nanos: AtomicU64::new(to_nanos(instant)),
+        }
+    }
+
+    /// Creates an atomic instant holding "now".
+    #[inline]
+    pub fn now() -> Self {
+        Self::new(Instant::now())
+    }
+
+    /// Loads the instant, see [`AtomicU64::load`] for `order`.
+    #[must_use]
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Instant {
+        from_nanos(self.nanos.load(order))
+    }
+
+    /// Stores `instant`, see [`AtomicU64::store`] for `order`.
+    #[inline]
+    pub fn store(&self, instant: Instant, order: Ordering) {
+        self.nanos.store(to_nanos(instant), order)
+    }
+
+    /// Stores "now", returning the instant stored.
+    #[inline]
+    pub fn store_now(&self, order: Ordering) -> Instant {
+        let now = Instant::now();
+        self.store(now, order);
+        now
+    }
+
+    /// Stores `instant` if it is later than the current one, returning the
+    /// previous one, see [`AtomicU64::fetch_max`] for `order`.
+    ///
+    /// Unlike [`AtomicInstant::store`], threads racing to record their own
+    /// reading never move the instant backwards.
+    #[inline]
+    pub fn fetch_max(&self, instant: Instant, order: Ordering) -> Instant {
+        from_nanos(self.nanos.fetch_max(to_nanos(instant), order))
+    }
+
+    /// Consumes the atomic instant, returning the instant it holds.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Instant {
+        from_nanos(self.nanos.into_inner())
+    }
+}
+
+impl From<Instant> for AtomicInstant {
+    #[inline]
+    fn from(instant: Instant) -> Self {
+        Self::new(instant)
+    }
+}
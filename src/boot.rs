@@ -0,0 +1,146 @@
+use core::{fmt, time::Duration};
+
+use crate::raw;
+
+/// A measurement of a monotonically nondecreasing clock that keeps counting
+/// while the system is suspended.
+///
+/// [`Instant`](crate::Instant) stops during a suspend on most platforms, so
+/// a timer measured with it is late by the time the machine slept. A
+/// `BootInstant` suits timers meant to expire on real elapsed time, like
+/// session-idle timeouts on laptops.
+///
+/// It reads `CLOCK_BOOTTIME` on Linux and OpenBSD, and `CLOCK_MONOTONIC` on
+/// Apple platforms, where that clock counts sleep time. FreeBSD, DragonFly
+/// and NetBSD have no such clock: it reads `CLOCK_UPTIME` on the first two,
+/// where `CLOCK_BOOTTIME` is an alias of it, and `CLOCK_MONOTONIC` on NetBSD,
+/// neither of which counts the time spent suspended.
+///
+/// Boot instants are only comparable with each other.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread::sleep, time::Duration};
+/// use unix_clock::BootInstant;
+///
+/// let start = BootInstant::now();
+/// sleep(Duration::from_millis(10));
+/// assert!(start.elapsed() >= Duration::from_millis(10));
+/// assert!(BootInstant::now() - start >= Duration::from_millis(10));
+/// assert_eq!(start.duration_since(BootInstant::now()), Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BootInstant {
+    t: raw::Timespec,
+}
+
+impl BootInstant {
+    /// Returns a boot instant corresponding to "now".
+    #[must_use]
+    #[inline]
+    pub fn now() -> Self {
+        Self {
+            t: raw::now_or_panic(raw::BOOT_INSTANT_CLOCKID),
+        }
+    }
+
+    /// Returns the amount of time elapsed from another boot instant to this
+    /// one, or zero duration if that instant is later than this one.
+    #[must_use]
+    #[inline]
+    pub fn duration_since(&self, earlier: BootInstant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns the amount of time elapsed from another boot instant to this
+    /// one, or None if that instant is later than this one.
+    #[must_use]
+    #[inline]
+    pub fn checked_duration_since(&self, earlier: BootInstant) -> Option<Duration> {
+        self.t.sub_timespec(&earlier.t).ok()
+    }
+
+    /// Returns the amount of time elapsed from another boot instant to this
+    /// one, or zero duration if that instant is later than this one.
+    #[must_use]
+    #[inline]
+    pub fn saturating_duration_since(&self, earlier: BootInstant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns the amount of time elapsed since this boot instant, suspended
+    /// time included where the platform counts it.
+    #[must_use]
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        BootInstant::now() - *self
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be
+    /// represented, `None` otherwise.
+    #[inline]
+    pub fn checked_add(&self, duration: Duration) -> Option<BootInstant> {
+        self.t.checked_add_duration(&duration).map(|t| Self { t })
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be
+    /// represented, `None` otherwise.
+    #[inline]
+    pub fn checked_sub(&self, duration: Duration) -> Option<BootInstant> {
+        self.t.checked_sub_duration(&duration).map(|t| Self { t })
+    }
+}
+
+impl core::ops::Add<Duration> for BootInstant {
+    type Output = BootInstant;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure. See [`BootInstant::checked_add`] for a version without panic.
+    fn add(self, other: Duration) -> BootInstant {
+        self.checked_add(other)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl core::ops::AddAssign<Duration> for BootInstant {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub<Duration> for BootInstant {
+    type Output = BootInstant;
+
+    fn sub(self, other: Duration) -> BootInstant {
+        self.checked_sub(other)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl core::ops::SubAssign<Duration> for BootInstant {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Sub<BootInstant> for BootInstant {
+    type Output = Duration;
+
+    /// Returns the amount of time elapsed from another boot instant to this
+    /// one, or zero duration if that instant is later than this one.
+    fn sub(self, other: BootInstant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+impl fmt::Debug for BootInstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BootInstant")
+            .field("secs", &self.t.secs())
+            .field("nsecs", &self.t.nsecs())
+            .finish()
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+
+use crate::Instant;
+
+/// Waits for a value on `rx` until `deadline`.
+///
+/// Unlike a bare [`Receiver::recv_timeout`] with the time left computed once,
+/// the wait is resumed until `deadline` has passed on this crate's clock: std
+/// measures its timeouts with its own clock, which can disagree with
+/// [`Instant`] (e.g. with the `boottime-instant` feature across a suspend).
+/// Once the deadline has passed, a value that is already queued is still
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::{sync::mpsc, thread, time::Duration};
+/// use unix_clock::{recv_deadline, Instant};
+///
+/// let (tx, rx) = mpsc::channel();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(10));
+///     tx.send(42).unwrap();
+/// });
+/// let deadline = Instant::now() + Duration::from_secs(10);
+/// assert_eq!(recv_deadline(&rx, deadline), Ok(42));
+///
+/// // the sender is kept alive, so the deadline is what ends the wait
+/// let (_tx, rx) = mpsc::channel::<()>();
+/// let deadline = Instant::now() + Duration::from_millis(20);
+/// assert_eq!(
+///     recv_deadline(&rx, deadline),
+///     Err(mpsc::RecvTimeoutError::Timeout)
+/// );
+/// assert!(Instant::now() >= deadline);
+/// ```
+pub fn recv_deadline<T>(rx: &Receiver<T>, deadline: Instant) -> Result<T, RecvTimeoutError> {
+    loop {
+        match deadline.checked_duration_since(Instant::now()) {
+            Some(left) if !left.is_zero() => match rx.recv_timeout(left) {
+                Err(RecvTimeoutError::Timeout) => continue,
+                res => return res,
+            },
+            _ => {
+                return rx.try_recv().map_err(|err| match err {
+                    TryRecvError::Empty => RecvTimeoutError::Timeout,
+                    TryRecvError::Disconnected => RecvTimeoutError::Disconnected,
+                })
+            }
+        }
+    }
+}
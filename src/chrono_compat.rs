@@ -0,0 +1,131 @@
+// The infallible conversion is the one from chrono: chrono only represents
+// the ±262 000 years around year 0, far fewer than the `i64` seconds of a
+// `SystemTime`, so the conversion to chrono is the `TryFrom`. Where `time_t`
+// is only 32 bits wide, on FreeBSD i386 and the 32-bit Apple targets, a
+// `SystemTime` doesn't cover chrono's range either: both are a `TryFrom`.
+
+use chrono::{DateTime, Utc};
+
+use crate::{Overflow, SystemTime};
+
+const NSEC_PER_SEC: u32 = 1_000_000_000;
+
+// The seconds and nanoseconds since the epoch of `value`. chrono represents a
+// leap second as an extra second of nanoseconds: it is folded into the next
+// second, which doesn't overflow within chrono's range.
+#[inline]
+fn timestamp_parts(value: &DateTime<Utc>) -> (i64, u32) {
+    let (secs, nsecs) = (value.timestamp(), value.timestamp_subsec_nanos());
+    if nsecs >= NSEC_PER_SEC {
+        (secs + 1, nsecs - NSEC_PER_SEC)
+    } else {
+        (secs, nsecs)
+    }
+}
+
+#[cfg(not(any(
+    all(target_os = "freebsd", target_arch = "x86"),
+    all(
+        any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "watchos",
+            target_os = "tvos"
+        ),
+        target_pointer_width = "32"
+    )
+)))]
+impl From<DateTime<Utc>> for SystemTime {
+    /// Converts from a chrono UTC date time, which is always representable
+    /// with a 64-bit `time_t`.
+    ///
+    /// A leap second, which chrono represents as an extra second of
+    /// nanoseconds, is folded into the next second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let dt = DateTime::from_timestamp(1_700_000_000, 5).unwrap();
+    /// assert_eq!(SystemTime::from(dt), SystemTime::new(1_700_000_000, 5));
+    /// ```
+    #[inline]
+    fn from(value: DateTime<Utc>) -> Self {
+        let (secs, nsecs) = timestamp_parts(&value);
+        SystemTime::new(secs, nsecs)
+    }
+}
+
+#[cfg(any(
+    all(target_os = "freebsd", target_arch = "x86"),
+    all(
+        any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "watchos",
+            target_os = "tvos"
+        ),
+        target_pointer_width = "32"
+    )
+))]
+impl TryFrom<DateTime<Utc>> for SystemTime {
+    type Error = Overflow;
+
+    /// Converts from a chrono UTC date time, failing if it is outside of the
+    /// years 1901 to 2038 a 32-bit `time_t` can represent.
+    ///
+    /// A leap second, which chrono represents as an extra second of
+    /// nanoseconds, is folded into the next second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::DateTime;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let dt = DateTime::from_timestamp(1_700_000_000, 5).unwrap();
+    /// assert_eq!(SystemTime::try_from(dt), Ok(SystemTime::new(1_700_000_000, 5)));
+    /// let dt = DateTime::from_timestamp(1 << 32, 0).unwrap();
+    /// assert!(SystemTime::try_from(dt).is_err());
+    /// ```
+    fn try_from(value: DateTime<Utc>) -> Result<Self, Self::Error> {
+        let (secs, nsecs) = timestamp_parts(&value);
+        if secs < crate::raw::Timespec::MIN.secs() {
+            Err(Overflow { at_max: false })
+        } else if secs > crate::raw::Timespec::MAX.secs() {
+            Err(Overflow { at_max: true })
+        } else {
+            Ok(SystemTime::new(secs, nsecs))
+        }
+    }
+}
+
+impl TryFrom<SystemTime> for DateTime<Utc> {
+    type Error = Overflow;
+
+    /// Converts to a chrono UTC date time, failing if `value` is outside of
+    /// the ±262 000 years around year 0 chrono can represent.
+    ///
+    /// Hence a `TryFrom`, while the conversion from chrono is a `From` where
+    /// `time_t` is 64 bits wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use unix_clock::{raw::Timespec, SystemTime};
+    ///
+    /// let t = SystemTime::new(-1, 500_000_000);
+    /// let dt = DateTime::<Utc>::try_from(t).unwrap();
+    /// assert_eq!((dt.timestamp(), dt.timestamp_subsec_nanos()), (-1, 500_000_000));
+    /// # if Timespec::MAX.secs() == i64::MAX {
+    /// assert!(DateTime::<Utc>::try_from(SystemTime::from(Timespec::MAX)).is_err());
+    /// # }
+    /// ```
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        let (secs, nsecs) = value.t.normalized_parts();
+        DateTime::from_timestamp(secs, nsecs).ok_or(Overflow { at_max: secs >= 0 })
+    }
+}
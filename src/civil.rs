@@ -0,0 +1,199 @@
+//! Proleptic Gregorian calendar arithmetic.
+//!
+//! Algorithms from Howard Hinnant's
+//! [`chrono`-Compatible Low-Level Date Algorithms](https://howardhinnant.github.io/date_algorithms.html).
+
+use core::time::Duration;
+
+pub(crate) const SECS_PER_DAY: i64 = 86_400;
+
+/// A span of time broken into days, hours, minutes and seconds, as returned
+/// by [`SystemTime::calendar_diff`](crate::SystemTime::calendar_diff).
+///
+/// Months and years are left out as their length varies, every component
+/// here is exact.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDuration {
+    /// `true` if the span goes backwards in time.
+    pub negative: bool,
+    /// Whole days of 86 400 seconds.
+    pub days: u64,
+    /// In `0..24`.
+    pub hours: u8,
+    /// In `0..60`.
+    pub minutes: u8,
+    /// In `0..60`.
+    pub seconds: u8,
+    /// In `0..1_000_000_000`.
+    pub nanos: u32,
+}
+
+impl CalendarDuration {
+    /// Breaks the magnitude `d` of a span into its components.
+    #[must_use]
+    pub const fn new(d: Duration, negative: bool) -> Self {
+        let secs = d.as_secs();
+        Self {
+            negative,
+            days: secs / SECS_PER_DAY as u64,
+            hours: (secs / 3_600 % 24) as u8,
+            minutes: (secs / 60 % 60) as u8,
+            seconds: (secs % 60) as u8,
+            nanos: d.subsec_nanos(),
+        }
+    }
+}
+
+/// A day of the week, as returned by
+/// [`SystemTime::weekday`](crate::SystemTime::weekday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the weekday of the day `days` days after 1970-01-01, a
+    /// Thursday.
+    #[inline]
+    pub(crate) const fn from_days(days: i64) -> Self {
+        match (days + 3).rem_euclid(7) {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+}
+
+#[inline]
+pub(crate) const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+#[inline]
+pub(crate) const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of the given date.
+///
+/// `month` must be in `1..=12` and `day` in `1..=days_in_month(year, month)`.
+pub(crate) const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the year, month and day of the day `days` days after 1970-01-01.
+pub(crate) const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    // The era starts in March, January and February belong to the next year.
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Returns the year containing the day `days` days after 1970-01-01.
+#[inline]
+pub(crate) const fn year_from_days(days: i64) -> i64 {
+    civil_from_days(days).0
+}
+
+/// Formats as an RFC 3339 timestamp in UTC, e.g. `2024-05-01T12:34:56.789Z`.
+///
+/// The fraction of a second is left out when zero, otherwise rendered with 3,
+/// 6 or 9 digits, as few as needed to be exact. Years outside `0..=9999`,
+/// which RFC 3339 can't express, get an explicit sign as in ISO 8601's
+/// expanded representation, e.g. `-0001-12-31T00:00:00Z`.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::SystemTime;
+///
+/// assert_eq!(SystemTime::UNIX_EPOCH.to_string(), "1970-01-01T00:00:00Z");
+/// assert_eq!(
+///     SystemTime::new(1_714_566_896, 789_000_000).to_string(),
+///     "2024-05-01T12:34:56.789Z"
+/// );
+/// assert_eq!(
+///     SystemTime::new(-1, 999_999_999).to_string(),
+///     "1969-12-31T23:59:59.999999999Z"
+/// );
+/// ```
+#[cfg(feature = "rfc3339")]
+impl core::fmt::Display for crate::SystemTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (secs, nanos) = self.t.normalized_parts();
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}", year)?;
+        } else {
+            write!(f, "{:+05}", year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            month,
+            day,
+            secs_of_day / 3_600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60
+        )?;
+        match nanos {
+            0 => f.write_str("Z"),
+            _ if nanos % 1_000_000 == 0 => write!(f, ".{:03}Z", nanos / 1_000_000),
+            _ if nanos % 1_000 == 0 => write!(f, ".{:06}Z", nanos / 1_000),
+            _ => write!(f, ".{:09}Z", nanos),
+        }
+    }
+}
+
+#[cfg(all(feature = "rfc3339", feature = "alloc"))]
+impl crate::SystemTime {
+    /// Returns `self` as an RFC 3339 timestamp in UTC, see the
+    /// [`Display`](core::fmt::Display) implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(
+    ///     SystemTime::new(951_827_400, 500_000).to_rfc3339(),
+    ///     "2000-02-29T12:30:00.000500Z"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_rfc3339(&self) -> alloc::string::String {
+        alloc::string::ToString::to_string(self)
+    }
+}
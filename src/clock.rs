@@ -0,0 +1,160 @@
+#[cfg(feature = "test-util")]
+use core::{cell::Cell, time::Duration};
+
+use crate::{Instant, SystemTime};
+
+/// A source of the current time, to be passed to time-dependent code instead
+/// of calling [`Instant::now`] and [`SystemTime::now`] directly.
+///
+/// [`SystemClock`] reads the system clocks; with the `test-util` feature,
+/// `ManualClock` only moves when told to, which makes such code testable.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Clock, Instant, SystemClock};
+///
+/// struct Session<C> {
+///     clock: C,
+///     last_seen: Instant,
+/// }
+///
+/// impl<C: Clock> Session<C> {
+///     fn is_idle(&self) -> bool {
+///         self.clock.now_instant() - self.last_seen > Duration::from_secs(300)
+///     }
+/// }
+///
+/// let session = Session {
+///     clock: SystemClock,
+///     last_seen: Instant::now(),
+/// };
+/// assert!(!session.is_idle());
+/// ```
+pub trait Clock {
+    /// Returns the monotonic time corresponding to "now".
+    fn now_instant(&self) -> Instant;
+
+    /// Returns the system time corresponding to "now".
+    fn now_system(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline]
+    fn now_instant(&self) -> Instant {
+        (**self).now_instant()
+    }
+
+    #[inline]
+    fn now_system(&self) -> SystemTime {
+        (**self).now_system()
+    }
+}
+
+/// The [`Clock`] reading [`Instant::now`] and [`SystemTime::now`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now_instant(&self) -> Instant {
+        Instant::now()
+    }
+
+    #[inline]
+    fn now_system(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A [`Clock`] standing still until advanced, for tests.
+///
+/// Both readings move together with [`ManualClock::advance`], while
+/// [`ManualClock::set_system`] steps the system time alone, like an
+/// administrator or NTP setting the wall clock. The clock is meant to be
+/// shared by reference within a thread: it isn't `Sync`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Clock, ManualClock, SystemTime};
+///
+/// let clock = ManualClock::default();
+/// let start = clock.now_instant();
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(clock.now_instant() - start, Duration::from_secs(5));
+/// assert_eq!(clock.now_system(), SystemTime::new(5, 0));
+///
+/// // the wall clock goes backwards, the monotonic one doesn't
+/// clock.set_system(SystemTime::UNIX_EPOCH);
+/// assert_eq!(clock.now_instant() - start, Duration::from_secs(5));
+/// ```
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManualClock {
+    instant: Cell<Instant>,
+    system: Cell<SystemTime>,
+}
+
+#[cfg(feature = "test-util")]
+impl ManualClock {
+    /// Creates a clock reading `instant` and `system`.
+    #[inline]
+    pub const fn new(instant: Instant, system: SystemTime) -> Self {
+        Self {
+            instant: Cell::new(instant),
+            system: Cell::new(system),
+        }
+    }
+
+    /// Creates a clock reading the current time of the system clocks.
+    #[inline]
+    pub fn starting_now() -> Self {
+        Self::new(Instant::now(), SystemTime::now())
+    }
+
+    /// Moves both readings forward by `duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either reading overflows.
+    #[track_caller]
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        self.instant.set(self.instant.get() + duration);
+        self.system.set(self.system.get() + duration);
+    }
+
+    /// Sets the system time reading, leaving the monotonic one untouched.
+    #[inline]
+    pub fn set_system(&self, system: SystemTime) {
+        self.system.set(system)
+    }
+}
+
+/// Reads the zero of the monotonic clock and the Unix epoch.
+#[cfg(feature = "test-util")]
+impl Default for ManualClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new(
+            Instant::from_timespec(crate::raw::Timespec::zero()),
+            SystemTime::UNIX_EPOCH,
+        )
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl Clock for ManualClock {
+    #[inline]
+    fn now_instant(&self) -> Instant {
+        self.instant.get()
+    }
+
+    #[inline]
+    fn now_system(&self) -> SystemTime {
+        self.system.get()
+    }
+}
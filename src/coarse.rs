@@ -0,0 +1,154 @@
+use core::time::Duration;
+
+use crate::{raw, SystemTime};
+
+/// A cheap wall clock for timestamping log lines.
+///
+/// Reads the coarse realtime clock, a value cached by the kernel at every
+/// tick instead of a full time counter query: on Linux `CLOCK_REALTIME_COARSE`
+/// and on FreeBSD and DragonFly `CLOCK_REALTIME_FAST`. Readings are behind the
+/// real time by up to one tick, typically 1 to 10 milliseconds depending on the
+/// kernel configuration.
+///
+/// Platforms without a coarse realtime clock fall back to [`SystemTime::now`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{CoarseWallClock, SystemTime};
+///
+/// let coarse = CoarseWallClock.now();
+/// let precise = SystemTime::now();
+/// let skew = match precise.duration_since(coarse) {
+///     Ok(d) => d,
+///     Err(e) => e.duration(),
+/// };
+/// assert!(skew < Duration::from_millis(50));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CoarseWallClock;
+
+impl CoarseWallClock {
+    /// The default of [`CoarseWallClock::set_max_staleness`], generous enough
+    /// to absorb a preemption between the two reads.
+    pub const DEFAULT_MAX_STALENESS: Duration = Duration::from_secs(1);
+
+    /// Returns the system time corresponding to "now", up to one tick old.
+    ///
+    /// In debug builds one reading out of 256 is checked against a precise
+    /// [`SystemTime::now`], see [`CoarseWallClock::set_max_staleness`].
+    #[must_use]
+    #[inline]
+    pub fn now(&self) -> SystemTime {
+        let now = SystemTime {
+            t: raw::now_or_panic(raw::COARSE_REALTIME_CLOCKID),
+        };
+        #[cfg(all(debug_assertions, target_has_atomic = "64"))]
+        freshness::check(now);
+        now
+    }
+
+    /// Sets how far behind the precise clock a reading may lag before the
+    /// debug assertions of [`CoarseWallClock::now`] fail, for every coarse
+    /// clock of the process.
+    ///
+    /// The check catches a cache that stopped being refreshed, e.g. kernels
+    /// without a working coarse clock. Readings ahead of the precise clock,
+    /// as after the system time is stepped back, are never reported. Release
+    /// builds don't check, this is a no-op there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::CoarseWallClock;
+    ///
+    /// CoarseWallClock::set_max_staleness(Duration::from_millis(100));
+    /// let _ = CoarseWallClock.now();
+    /// ```
+    #[inline]
+    pub fn set_max_staleness(bound: Duration) {
+        #[cfg(all(debug_assertions, target_has_atomic = "64"))]
+        freshness::set_bound(bound);
+        #[cfg(not(all(debug_assertions, target_has_atomic = "64")))]
+        let _ = bound;
+    }
+}
+
+#[cfg(all(debug_assertions, target_has_atomic = "64"))]
+mod freshness {
+    use core::{
+        sync::atomic::{AtomicU32, AtomicU64, Ordering},
+        time::Duration,
+    };
+
+    use super::CoarseWallClock;
+    use crate::SystemTime;
+
+    const SAMPLE_EVERY: u32 = 256;
+
+    // In nanoseconds, saturating at ~584 years.
+    static MAX_STALENESS: AtomicU64 =
+        AtomicU64::new(CoarseWallClock::DEFAULT_MAX_STALENESS.as_secs() * 1_000_000_000);
+    static READS: AtomicU32 = AtomicU32::new(0);
+
+    #[inline]
+    pub(super) fn set_bound(bound: Duration) {
+        let nanos = u64::try_from(bound.as_nanos()).unwrap_or(u64::MAX);
+        MAX_STALENESS.store(nanos, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(super) fn check(coarse: SystemTime) {
+        if READS.fetch_add(1, Ordering::Relaxed) % SAMPLE_EVERY != 0 {
+            return;
+        }
+
+        let bound = Duration::from_nanos(MAX_STALENESS.load(Ordering::Relaxed));
+        if let Ok(lag) = SystemTime::now().duration_since(coarse) {
+            assert!(
+                lag <= bound,
+                "coarse wall clock reading {:?} behind the precise clock, over the {:?} bound",
+                lag,
+                bound
+            );
+        }
+    }
+}
+
+impl SystemTime {
+    /// Returns the current system time truncated to the second, as cheaply as
+    /// the platform allows.
+    ///
+    /// On FreeBSD and DragonFly it reads `CLOCK_SECOND`, the second cached by
+    /// the kernel, without any time counter query. Elsewhere it truncates a
+    /// [`CoarseWallClock`] reading, so it may lag the real second by one tick
+    /// around second boundaries.
+    ///
+    /// Meant for high-rate logging that only needs second granularity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let second = SystemTime::now_second();
+    /// assert_eq!(second.duration_since(SystemTime::UNIX_EPOCH).unwrap().subsec_nanos(), 0);
+    /// assert!(SystemTime::now().duration_since(second).unwrap() < Duration::from_secs(2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn now_second() -> SystemTime {
+        #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+        let clockid = raw::ClockId::Second;
+        #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly")))]
+        let clockid = raw::COARSE_REALTIME_CLOCKID;
+
+        let t = raw::now_or_panic(clockid);
+        SystemTime {
+            t: raw::Timespec::new(t.secs(), 0),
+        }
+    }
+}
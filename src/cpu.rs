@@ -0,0 +1,106 @@
+use core::time::Duration;
+
+use crate::{raw, Errno};
+
+#[inline]
+fn cpu_time(clock: raw::ClockId) -> Result<Duration, Errno> {
+    // CPU clocks start at zero and never go backwards.
+    raw::Timespec::now(clock).map(|t| {
+        t.sub_timespec(&raw::Timespec::zero())
+            .unwrap_or(Duration::ZERO)
+    })
+}
+
+/// Returns the CPU time, in user and kernel mode, consumed by all the threads
+/// of the process since it started.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::process_cpu_time;
+///
+/// let before = process_cpu_time().unwrap();
+/// assert!(process_cpu_time().unwrap() >= before);
+/// ```
+#[inline]
+pub fn process_cpu_time() -> Result<Duration, Errno> {
+    cpu_time(raw::ClockId::ProcessCputimeId)
+}
+
+/// Returns the CPU time, in user and kernel mode, consumed by the calling
+/// thread since it started.
+///
+/// Time spent sleeping or blocked doesn't count.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread::sleep, time::Duration};
+/// use unix_clock::thread_cpu_time;
+///
+/// let before = thread_cpu_time().unwrap();
+/// sleep(Duration::from_millis(50));
+/// assert!(thread_cpu_time().unwrap() - before < Duration::from_millis(50));
+/// ```
+#[inline]
+pub fn thread_cpu_time() -> Result<Duration, Errno> {
+    cpu_time(raw::ClockId::ThreadCputimeId)
+}
+
+/// Measures the CPU time consumed by a code region, either by the whole
+/// process or by the calling thread.
+///
+/// A timer started with [`CpuTimer::start_thread`] reads the CPU clock of the
+/// thread calling [`CpuTimer::elapsed`]: it is only meaningful on the thread
+/// that started it.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread::sleep, time::Duration};
+/// use unix_clock::CpuTimer;
+///
+/// let timer = CpuTimer::start_thread().unwrap();
+/// sleep(Duration::from_millis(50));
+/// // sleeping doesn't use the CPU
+/// assert!(timer.elapsed() < Duration::from_millis(50));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CpuTimer {
+    clock: raw::ClockId,
+    start: Duration,
+}
+
+impl CpuTimer {
+    /// Starts measuring the CPU time consumed by the process.
+    #[inline]
+    pub fn start() -> Result<Self, Errno> {
+        Self::start_with(raw::ClockId::ProcessCputimeId)
+    }
+
+    /// Starts measuring the CPU time consumed by the calling thread.
+    #[inline]
+    pub fn start_thread() -> Result<Self, Errno> {
+        Self::start_with(raw::ClockId::ThreadCputimeId)
+    }
+
+    #[inline]
+    fn start_with(clock: raw::ClockId) -> Result<Self, Errno> {
+        cpu_time(clock).map(|start| Self { clock, start })
+    }
+
+    /// Returns the CPU time consumed since the timer started.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CPU clock can't be read, although it could be when the
+    /// timer started.
+    #[must_use]
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        match cpu_time(self.clock) {
+            Ok(now) => now.saturating_sub(self.start),
+            Err(errno) => raw::clock_gettime_failed(self.clock, errno),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use core::time::Duration;
+
+use crate::{Errno, Instant};
+
+/// A point in time by which an operation must complete.
+///
+/// Deadlines compose across call chains: a server handling a request with a
+/// deadline can give each downstream call a [`child`](Deadline::child)
+/// deadline that never outlives its own.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::Deadline;
+///
+/// let request = Deadline::after(Duration::from_secs(5));
+/// let downstream = request.child(Duration::from_secs(1));
+/// assert!(downstream <= request);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline {
+    at: Instant,
+}
+
+impl Deadline {
+    /// Creates a deadline expiring at `at`.
+    #[inline]
+    pub const fn at(at: Instant) -> Self {
+        Self { at }
+    }
+
+    /// Creates a deadline expiring `timeout` from now.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    #[inline]
+    pub fn after(timeout: Duration) -> Self {
+        Self::at(Instant::now() + timeout)
+    }
+
+    /// Returns the instant at which this deadline expires.
+    #[must_use]
+    #[inline]
+    pub const fn instant(&self) -> Instant {
+        self.at
+    }
+
+    /// Returns the time left before the deadline expires, or `None` if it
+    /// already has.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Deadline;
+    ///
+    /// let deadline = Deadline::after(Duration::from_secs(5));
+    /// assert!(deadline.remaining().unwrap() <= Duration::from_secs(5));
+    /// assert_eq!(Deadline::after(Duration::ZERO).remaining(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining_at(Instant::now())
+    }
+
+    /// Returns the time left at `now` before the deadline expires, or `None`
+    /// if it has expired by then.
+    #[must_use]
+    #[inline]
+    pub fn remaining_at(&self, now: Instant) -> Option<Duration> {
+        match self.at.checked_duration_since(now) {
+            Some(left) if !left.is_zero() => Some(left),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the deadline has expired, i.e. is not later than now.
+    #[must_use]
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Instant::now())
+    }
+
+    /// Returns `true` if the deadline has expired by `now`.
+    #[must_use]
+    #[inline]
+    pub fn is_expired_at(&self, now: Instant) -> bool {
+        self.at <= now
+    }
+
+    /// Puts the current thread to sleep until the deadline expires, see
+    /// [`sleep_until`](crate::sleep_until).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Deadline;
+    ///
+    /// let deadline = Deadline::after(Duration::from_millis(10));
+    /// deadline.sleep().unwrap();
+    /// assert!(deadline.is_expired());
+    /// ```
+    #[inline]
+    pub fn sleep(&self) -> Result<(), Errno> {
+        crate::sleep_until(self.at)
+    }
+
+    /// Returns a deadline expiring at most `max` from now, but never later than
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Deadline;
+    ///
+    /// // the parent is tighter
+    /// let parent = Deadline::after(Duration::from_millis(100));
+    /// assert_eq!(parent.child(Duration::from_secs(10)), parent);
+    ///
+    /// // the child budget is tighter
+    /// let parent = Deadline::after(Duration::from_secs(10));
+    /// let child = parent.child(Duration::from_millis(100));
+    /// assert!(child < parent);
+    /// ```
+    #[must_use]
+    pub fn child(&self, max: Duration) -> Deadline {
+        match Instant::now().checked_add(max) {
+            Some(at) => Self::at(self.at.min(at)),
+            None => *self,
+        }
+    }
+}
+
+impl From<Instant> for Deadline {
+    #[inline]
+    fn from(value: Instant) -> Self {
+        Self::at(value)
+    }
+}
+
+impl From<Deadline> for Instant {
+    #[inline]
+    fn from(value: Deadline) -> Self {
+        value.at
+    }
+}
@@ -0,0 +1,93 @@
+use core::time::Duration;
+
+use crate::{Instant, SystemTime};
+
+/// A time-to-live whose expiry doesn't depend on the wall clock.
+///
+/// `SystemTime::now() + ttl` expiries move whenever the wall clock is
+/// stepped, e.g. by NTP or an administrator: entries expire early after a
+/// step forward and live on after a step backward. `WallExpiry` records the
+/// wall-clock time of its creation along with an [`Instant`], only the latter
+/// deciding the expiry. The wall-clock anchor is kept for display and
+/// persistence, see [`WallExpiry::expires_at`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Instant, SystemTime, WallExpiry};
+///
+/// // the wall clock stepped a day forward since the entry was created
+/// let created = SystemTime::now() - Duration::from_secs(86_400);
+/// let entry = WallExpiry::with_anchors(created, Instant::now(), Duration::from_secs(60));
+/// assert!(entry.expires_at() < SystemTime::now());
+/// assert!(!entry.is_expired());
+///
+/// // a minute of monotonic time passed, whatever the wall clock says
+/// let mono = Instant::now().checked_sub(Duration::from_secs(61)).unwrap();
+/// let entry = WallExpiry::with_anchors(SystemTime::now(), mono, Duration::from_secs(60));
+/// assert!(entry.expires_at() > SystemTime::now());
+/// assert!(entry.is_expired());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WallExpiry {
+    wall: SystemTime,
+    mono: Instant,
+    ttl: Duration,
+}
+
+impl WallExpiry {
+    /// Creates an expiry `ttl` from now.
+    #[inline]
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_anchors(SystemTime::now(), Instant::now(), ttl)
+    }
+
+    /// Creates an expiry `ttl` after the moment at which the wall clock read
+    /// `wall` and the monotonic clock read `mono`.
+    #[inline]
+    pub const fn with_anchors(wall: SystemTime, mono: Instant, ttl: Duration) -> Self {
+        Self { wall, mono, ttl }
+    }
+
+    /// Returns the time-to-live.
+    #[must_use]
+    #[inline]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the wall-clock time at which this expiry was created.
+    #[must_use]
+    #[inline]
+    pub const fn created_at(&self) -> SystemTime {
+        self.wall
+    }
+
+    /// Returns the wall-clock time at which the entry expires, as seen from
+    /// its creation.
+    ///
+    /// Only meant for display: the wall clock may have been stepped since,
+    /// [`WallExpiry::is_expired`] is what decides the expiry. Saturates at
+    /// the latest representable time.
+    #[must_use]
+    #[inline]
+    pub fn expires_at(&self) -> SystemTime {
+        self.wall.checked_add(self.ttl).unwrap_or(SystemTime::MAX)
+    }
+
+    /// Returns `true` once `ttl` of monotonic time has elapsed since the
+    /// creation.
+    #[must_use]
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.mono.elapsed() >= self.ttl
+    }
+
+    /// Returns the monotonic time left before the expiry, zero if expired.
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> Duration {
+        self.ttl.saturating_sub(self.mono.elapsed())
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::{format, string::String};
+use core::time::Duration;
+
+const UNITS: [(&str, f64); 4] = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+
+/// Renders `d` with `sig` significant figures in the largest unit, among
+/// nanoseconds, microseconds, milliseconds and seconds, that keeps the value
+/// at least 1.
+///
+/// Every value rendered with the same `sig` has the same precision, which
+/// lines up benchmark tables. Nanoseconds are never printed with decimals, as
+/// durations don't have a finer precision. A `sig` of 0 is treated as 1.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::format_duration_sig;
+///
+/// assert_eq!(format_duration_sig(Duration::from_nanos(1_234_567), 3), "1.23 ms");
+/// assert_eq!(format_duration_sig(Duration::from_millis(4_561), 3), "4.56 s");
+/// assert_eq!(format_duration_sig(Duration::from_millis(4_561), 1), "5 s");
+/// assert_eq!(format_duration_sig(Duration::from_micros(12_345), 2), "12 ms");
+/// assert_eq!(format_duration_sig(Duration::from_secs(12_345), 2), "12000 s");
+/// assert_eq!(format_duration_sig(Duration::from_nanos(42), 4), "42 ns");
+/// assert_eq!(format_duration_sig(Duration::from_nanos(999_999), 3), "1.00 ms");
+/// assert_eq!(format_duration_sig(Duration::from_nanos(9_999), 3), "10.0 µs");
+/// assert_eq!(format_duration_sig(Duration::ZERO, 3), "0 ns");
+/// ```
+pub fn format_duration_sig(d: Duration, sig: u8) -> String {
+    let sig = u32::from(sig.max(1));
+    let nanos = d.as_nanos() as f64;
+    let mut unit = UNITS
+        .iter()
+        .rposition(|&(_, scale)| nanos >= scale)
+        .unwrap_or(0);
+
+    loop {
+        let (name, scale) = UNITS[unit];
+        let value = nanos / scale;
+        let digits = int_digits(value);
+        let mut number = render(value, digits, sig, unit == 0);
+
+        // Rounding carried into a new digit, e.g. 999.9 -> 1000.
+        let len = number.find('.').unwrap_or(number.len()) as u32;
+        if len > digits {
+            if len > 3 && unit + 1 < UNITS.len() {
+                unit += 1;
+                continue;
+            }
+            number = render(value, len, sig, unit == 0);
+        }
+
+        return format!("{} {}", number, name);
+    }
+}
+
+#[inline]
+fn int_digits(value: f64) -> u32 {
+    let mut digits = 1;
+    let mut bound = 10.0;
+    while value >= bound {
+        digits += 1;
+        bound *= 10.0;
+    }
+    digits
+}
+
+fn render(value: f64, digits: u32, sig: u32, integer: bool) -> String {
+    if digits >= sig {
+        let zeros = (digits - sig) as usize;
+        let mut scale = 1.0;
+        for _ in 0..zeros {
+            scale *= 10.0;
+        }
+        let mut number = format!("{:.0}", value / scale);
+        number.extend(core::iter::repeat('0').take(zeros));
+        number
+    } else if integer {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.*}", (sig - digits) as usize, value)
+    }
+}
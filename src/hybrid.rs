@@ -0,0 +1,80 @@
+use crate::{Instant, SystemTime};
+
+/// A wall clock that never goes backwards.
+///
+/// Every reading is the later of the current [`SystemTime`] and the previous
+/// reading advanced by the [`Instant`] time elapsed since then. If the system
+/// clock is stepped back (e.g. by NTP) the readings keep advancing at the
+/// monotonic pace instead of jumping back, which makes them suitable as
+/// ordered timestamps like the ones databases assign to writes.
+///
+/// After a backward step the readings stay ahead of the real wall time by the
+/// size of the step: they are ordered timestamps, not an accurate clock.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::HybridClock;
+///
+/// let mut clock = HybridClock::new();
+/// let first = clock.now();
+/// let second = clock.now();
+/// assert!(second >= first);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HybridClock {
+    last: Option<(SystemTime, Instant)>,
+}
+
+impl HybridClock {
+    /// Creates a clock whose first reading is the current system time.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Returns the current hybrid time.
+    #[inline]
+    pub fn now(&mut self) -> SystemTime {
+        self.observe(SystemTime::now(), Instant::now())
+    }
+
+    /// Returns the hybrid time corresponding to a system time reading `wall`
+    /// taken at `mono`.
+    ///
+    /// [`now`](HybridClock::now) calls it with the current clocks; calling it
+    /// directly allows feeding readings from another source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{HybridClock, Instant, SystemTime};
+    ///
+    /// let mut clock = HybridClock::new();
+    /// let mono = Instant::now();
+    /// let wall = SystemTime::new(1_000, 0);
+    /// assert_eq!(clock.observe(wall, mono), wall);
+    ///
+    /// // the system clock is stepped back by 10s while 1s passes
+    /// let t = clock.observe(SystemTime::new(991, 0), mono + Duration::from_secs(1));
+    /// assert_eq!(t, SystemTime::new(1_001, 0));
+    ///
+    /// // a forward step is followed
+    /// let t = clock.observe(SystemTime::new(2_000, 0), mono + Duration::from_secs(2));
+    /// assert_eq!(t, SystemTime::new(2_000, 0));
+    /// ```
+    pub fn observe(&mut self, wall: SystemTime, mono: Instant) -> SystemTime {
+        let t = match self.last {
+            Some((last_wall, last_mono)) => {
+                match last_wall.checked_add(mono.duration_since(last_mono)) {
+                    Some(advanced) => wall.max(advanced),
+                    None => wall.max(last_wall),
+                }
+            }
+            None => wall,
+        };
+        self.last = Some((t, mono));
+        t
+    }
+}
@@ -0,0 +1,260 @@
+use core::time::Duration;
+
+use crate::{raw, Errno, SystemTime};
+
+const NSEC_PER_SEC: i128 = 1_000_000_000;
+
+/// An entry of a leap second table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// The UTC time from which `tai_offset` applies, the midnight right after
+    /// the leap second.
+    pub at: SystemTime,
+    /// TAI − UTC in seconds from `at` on.
+    pub tai_offset: i32,
+}
+
+const fn leap(at: i64, tai_offset: i32) -> LeapSecond {
+    LeapSecond {
+        at: SystemTime::new(at, 0),
+        tai_offset,
+    }
+}
+
+/// The leap seconds announced by the IERS, none has been scheduled after 2017.
+///
+/// The first entry is the initial TAI − UTC offset of 1972 rather than a leap
+/// second.
+pub const IERS_LEAP_SECONDS: &[LeapSecond] = &[
+    leap(63_072_000, 10),    // 1972-01-01
+    leap(78_796_800, 11),    // 1972-07-01
+    leap(94_694_400, 12),    // 1973-01-01
+    leap(126_230_400, 13),   // 1974-01-01
+    leap(157_766_400, 14),   // 1975-01-01
+    leap(189_302_400, 15),   // 1976-01-01
+    leap(220_924_800, 16),   // 1977-01-01
+    leap(252_460_800, 17),   // 1978-01-01
+    leap(283_996_800, 18),   // 1979-01-01
+    leap(315_532_800, 19),   // 1980-01-01
+    leap(362_793_600, 20),   // 1981-07-01
+    leap(394_329_600, 21),   // 1982-07-01
+    leap(425_865_600, 22),   // 1983-07-01
+    leap(489_024_000, 23),   // 1985-07-01
+    leap(567_993_600, 24),   // 1988-01-01
+    leap(631_152_000, 25),   // 1990-01-01
+    leap(662_688_000, 26),   // 1991-01-01
+    leap(709_948_800, 27),   // 1992-07-01
+    leap(741_484_800, 28),   // 1993-07-01
+    leap(773_020_800, 29),   // 1994-07-01
+    leap(820_454_400, 30),   // 1996-01-01
+    leap(867_715_200, 31),   // 1997-07-01
+    leap(915_148_800, 32),   // 1999-01-01
+    leap(1_136_073_600, 33), // 2006-01-01
+    leap(1_230_768_000, 34), // 2009-01-01
+    leap(1_341_100_800, 35), // 2012-07-01
+    leap(1_435_708_800, 36), // 2015-07-01
+    leap(1_483_228_800, 37), // 2017-01-01
+];
+
+/// A leap smear: the conversion from TAI to a UTC that is slowed down (or sped
+/// up) around each leap second instead of repeating (or skipping) one.
+///
+/// With a smear every UTC second within `window` of a leap is stretched by a
+/// constant factor, so timestamps stay monotonic and uniformly spaced while
+/// matching the real UTC outside of the windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapSmear<'a> {
+    /// The leap second table, sorted by [`LeapSecond::at`]. Consecutive
+    /// entries must be further apart than `window`.
+    pub leaps: &'a [LeapSecond],
+    /// The duration of the smear, centered on each leap second.
+    pub window: Duration,
+}
+
+impl<'a> LeapSmear<'a> {
+    /// Creates a smear over `window` for the leap seconds in `leaps`.
+    #[inline]
+    pub const fn new(leaps: &'a [LeapSecond], window: Duration) -> Self {
+        Self { leaps, window }
+    }
+
+    /// Converts a TAI reading to smeared UTC.
+    ///
+    /// Before the first entry of the table its offset is applied unsmeared,
+    /// an empty table applies no offset at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{raw::Timespec, LeapSecond, LeapSmear, SystemTime};
+    ///
+    /// let leaps = [
+    ///     LeapSecond { at: SystemTime::new(0, 0), tai_offset: 10 },
+    ///     LeapSecond { at: SystemTime::new(1_000, 0), tai_offset: 11 },
+    /// ];
+    /// let smear = LeapSmear::new(&leaps, Duration::from_secs(100));
+    ///
+    /// // outside of the window the offsets are applied as they are
+    /// assert_eq!(smear.tai_to_utc(Timespec::new(900, 0)), SystemTime::new(890, 0));
+    /// assert_eq!(smear.tai_to_utc(Timespec::new(1_100, 0)), SystemTime::new(1_089, 0));
+    ///
+    /// // the window spans 101 TAI seconds and 100 UTC seconds
+    /// assert_eq!(smear.tai_to_utc(Timespec::new(960, 0)), SystemTime::new(950, 0));
+    /// assert_eq!(
+    ///     smear.tai_to_utc(Timespec::new(1_010, 500_000_000)),
+    ///     SystemTime::new(1_000, 0)
+    /// );
+    /// assert_eq!(smear.tai_to_utc(Timespec::new(1_061, 0)), SystemTime::new(1_050, 0));
+    /// ```
+    pub fn tai_to_utc(&self, tai: raw::Timespec) -> SystemTime {
+        let tai = tai.to_nanos_i128();
+        let window = self.window.as_nanos() as i128;
+
+        let mut offset = match self.leaps.first() {
+            Some(first) => first.tai_offset as i128 * NSEC_PER_SEC,
+            None => 0,
+        };
+        for leap in self.leaps.iter().skip(1) {
+            let next = leap.tai_offset as i128 * NSEC_PER_SEC;
+            let at = leap.at.t.to_nanos_i128();
+            let start = at - window / 2 + offset;
+            let end = at + (window - window / 2) + next;
+
+            if tai < start {
+                break;
+            }
+            if tai < end {
+                // `end - start` TAI nanoseconds are mapped onto `window` UTC
+                // nanoseconds.
+                return SystemTime::from_nanos_since_epoch(
+                    at - window / 2 + (tai - start) * window / (end - start),
+                );
+            }
+            offset = next;
+        }
+
+        SystemTime::from_nanos_since_epoch(tai - offset)
+    }
+}
+
+/// How [`SystemTime::now_mode`] reads the wall clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WallMode<'a> {
+    /// Reads `CLOCK_REALTIME`, like [`SystemTime::now`]: UTC as kept by the
+    /// kernel, which repeats or skips a second on leaps.
+    Kernel,
+    /// Reads `CLOCK_TAI` and converts it to UTC with the given smear.
+    ///
+    /// `CLOCK_TAI` is only correct if the TAI offset has been set in the
+    /// kernel (e.g. by chrony or ntpd), otherwise it reads the same as
+    /// `CLOCK_REALTIME`.
+    TaiSmeared(LeapSmear<'a>),
+}
+
+/// Whether a leap second is scheduled or being applied by the kernel, as
+/// returned by [`SystemTime::now_with_leap_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapStatus {
+    /// No leap second is pending.
+    None,
+    /// A second is inserted at the end of the current UTC day.
+    Insert,
+    /// A second is deleted at the end of the current UTC day.
+    Delete,
+    /// The inserted second is in progress: the realtime clock repeats the
+    /// last second of the day.
+    InProgress,
+}
+
+const TIME_OOP: i32 = 3;
+const STA_INS: i32 = 0x0010;
+const STA_DEL: i32 = 0x0020;
+const STA_NANO: i32 = 0x2000;
+
+impl SystemTime {
+    /// Returns the system time corresponding to "now", read according to `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use unix_clock::{LeapSmear, SystemTime, WallMode, IERS_LEAP_SECONDS};
+    ///
+    /// let smear = LeapSmear::new(IERS_LEAP_SECONDS, Duration::from_secs(86_400));
+    /// let now = SystemTime::now_mode(WallMode::TaiSmeared(smear)).unwrap();
+    /// ```
+    pub fn now_mode(mode: WallMode<'_>) -> Result<SystemTime, Errno> {
+        match mode {
+            WallMode::Kernel => raw::Timespec::now(raw::SYSTEM_TIME_CLOCKID).map(|t| Self { t }),
+            WallMode::TaiSmeared(smear) => {
+                raw::Timespec::now(raw::ClockId::InternationalAtomicTime)
+                    .map(|t| smear.tai_to_utc(t))
+            }
+        }
+    }
+
+    /// Returns the system time corresponding to "now" along with the leap
+    /// second status of the kernel, read atomically with `clock_adjtime(2)`.
+    ///
+    /// The status is only set if a time daemon has announced the leap second
+    /// to the kernel, usually a day in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{LeapStatus, SystemTime};
+    ///
+    /// let before = SystemTime::now();
+    /// let (now, status) = SystemTime::now_with_leap_status().unwrap();
+    /// assert!(now >= before);
+    /// match status {
+    ///     LeapStatus::None => println!("no leap second pending"),
+    ///     LeapStatus::Insert | LeapStatus::Delete => println!("leap second tonight"),
+    ///     LeapStatus::InProgress => println!("leap second in progress"),
+    /// }
+    /// ```
+    pub fn now_with_leap_status() -> Result<(SystemTime, LeapStatus), Errno> {
+        let mut timex = raw::Timex::default();
+        let state = raw::clock_adjtime(raw::SYSTEM_TIME_CLOCKID, &mut timex)?;
+
+        let nsecs = if timex.status & STA_NANO != 0 {
+            timex.time.tv_usec
+        } else {
+            timex.time.tv_usec * 1_000
+        };
+        let status = if state == TIME_OOP {
+            LeapStatus::InProgress
+        } else if timex.status & STA_INS != 0 {
+            LeapStatus::Insert
+        } else if timex.status & STA_DEL != 0 {
+            LeapStatus::Delete
+        } else {
+            LeapStatus::None
+        };
+        Ok((SystemTime::new(timex.time.tv_sec, nsecs as u32), status))
+    }
+
+    /// Returns `true` if the kernel implements the TAI clock needed by
+    /// [`WallMode::TaiSmeared`], `false` if callers have to fall back to the
+    /// realtime clock.
+    ///
+    /// The clock exists since Linux 3.10. Its offset from UTC is maintained
+    /// by the NTP daemon: if none has set it, TAI reads the same as UTC.
+    /// The probe is done once and cached, see [`ClockId::is_supported`].
+    ///
+    /// [`ClockId::is_supported`]: raw::ClockId::is_supported
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// let available = SystemTime::tai_available();
+    /// assert_eq!(SystemTime::tai_available(), available);
+    /// ```
+    #[inline]
+    pub fn tai_available() -> bool {
+        raw::ClockId::InternationalAtomicTime.is_supported()
+    }
+}
@@ -1,4 +1,8 @@
 #![cfg(unix)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::{fmt, time::Duration};
 
@@ -16,7 +20,79 @@ pub use bsd_errnos::Errno;
 #[cfg(target_os = "linux")]
 pub use linux_syscalls::Errno;
 
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+mod boot;
+#[cfg(feature = "std")]
+mod channel;
+#[cfg(feature = "chrono")]
+mod chrono_compat;
+mod civil;
+mod clock;
+mod coarse;
+mod cpu;
+mod deadline;
+mod expiry;
+#[cfg(feature = "alloc")]
+mod format;
+mod hybrid;
+#[cfg(target_os = "linux")]
+mod leap;
+#[cfg(feature = "alloc")]
+mod lru;
+mod pll;
+mod progress;
 pub mod raw;
+mod retry;
+mod scaled;
+mod scoped;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde_std_compat;
+mod shim;
+mod signed;
+mod sleep;
+#[cfg(feature = "alloc")]
+mod span;
+mod throttle;
+mod watchdog;
+
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicInstant;
+pub use boot::BootInstant;
+#[cfg(feature = "std")]
+pub use channel::recv_deadline;
+pub use civil::{CalendarDuration, Weekday};
+#[cfg(feature = "test-util")]
+pub use clock::ManualClock;
+pub use clock::{Clock, SystemClock};
+pub use coarse::CoarseWallClock;
+pub use cpu::{process_cpu_time, thread_cpu_time, CpuTimer};
+pub use deadline::Deadline;
+pub use expiry::WallExpiry;
+#[cfg(feature = "alloc")]
+pub use format::format_duration_sig;
+pub use hybrid::HybridClock;
+#[cfg(target_os = "linux")]
+pub use leap::{LeapSecond, LeapSmear, LeapStatus, WallMode, IERS_LEAP_SECONDS};
+#[cfg(feature = "alloc")]
+pub use lru::TimedLru;
+pub use pll::PhaseLockedClock;
+pub use progress::SmoothedElapsed;
+pub use retry::retry_with_backoff;
+pub use scaled::ScaledClock;
+pub use scoped::ScopedTimer;
+pub use signed::SignedDuration;
+pub use sleep::{
+    precise_sleep, precise_sleep_with_threshold, sleep_until, spin_until, DEFAULT_SPIN_THRESHOLD,
+};
+#[cfg(feature = "alloc")]
+pub use span::{SpanGuard, SpanRecorder, SpanTree};
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub use throttle::AtomicThrottle;
+pub use throttle::Throttle;
+#[cfg(feature = "std")]
+pub use watchdog::WatchdogThread;
+pub use watchdog::WatchdogTimer;
 
 #[allow(clippy::needless_doctest_main)]
 /// A measurement of a monotonically nondecreasing clock.
@@ -77,6 +153,15 @@ pub mod raw;
 /// [`elapsed`]: Instant::elapsed
 /// [`checked_duration_since`]: Instant::checked_duration_since
 ///
+/// ## Suspend
+///
+/// On Linux instants read `CLOCK_MONOTONIC`, which stops while the system is
+/// suspended: [`elapsed`] leaves out the time a laptop spent asleep. With the
+/// `boottime-instant` feature they read `CLOCK_BOOTTIME` instead, which counts
+/// it. The kernel reads both at the same cost, but `CLOCK_BOOTTIME` needs
+/// Linux 2.6.39 and, being stepped forward on resume, makes instants jump by
+/// the suspended time.
+///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Instant {
     t: raw::Timespec,
@@ -157,15 +242,509 @@ pub struct SystemTime {
 ///     Err(e) => println!("SystemTimeError difference: {:?}", e.duration()),
 /// }
 /// ```
+///
+/// With the `std` feature it implements `std::error::Error`, so it can be
+/// propagated with `?` into a `Box<dyn Error>`:
+///
+/// ```
+/// use unix_clock::SystemTime;
+///
+/// let err = SystemTime::new(0, 0)
+///     .duration_since(SystemTime::new(1, 500_000_000))
+///     .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "second time provided was later than self by 1.5s"
+/// );
+/// ```
 #[derive(Clone, Debug)]
 pub struct SystemTimeError(Duration);
 
+/// An error returned from [`SystemTime::from_civil`] when the broken-down
+/// components don't describe a valid point in time.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::SystemTime;
+///
+/// // 2023 is not a leap year
+/// assert!(SystemTime::from_civil(2023, 2, 29, 0, 0, 0, 0, 0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidDate(());
+
+/// An error returned from the `checked_add_reason` and `checked_sub_reason`
+/// methods on [`Instant`] and [`SystemTime`], and from the conversions between
+/// [`SystemTime`] and `std::time::SystemTime`, when the result is not
+/// representable.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Overflow, SystemTime};
+///
+/// let max = SystemTime::MAX;
+/// assert_eq!(
+///     max.checked_add_reason(Duration::from_nanos(1)),
+///     Err(Overflow { at_max: true })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Overflow {
+    /// `true` if the latest representable point in time was exceeded, `false`
+    /// if the earliest one was.
+    pub at_max: bool,
+}
+
+/// An error returned from [`SystemTime::from_unix_duration_checked`] when the
+/// timestamp falls outside the accepted window.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{OutOfRange, SystemTime};
+///
+/// let min = SystemTime::UNIX_EPOCH;
+/// let max = SystemTime::new(1_000, 0);
+/// assert_eq!(
+///     SystemTime::from_unix_duration_checked(Duration::from_secs(1_001), min, max),
+///     Err(OutOfRange { after_max: true })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfRange {
+    /// `true` if the timestamp is later than the window, `false` if it is
+    /// earlier.
+    pub after_max: bool,
+}
+
+// Index of the `step`-nanosecond bucket `t` falls in, counting from the
+// clock's zero. A non-positive `step` leaves every timestamp in its own bucket.
+#[inline]
+fn bucket(t: &raw::Timespec, step: i128) -> i128 {
+    let nanos = t.to_nanos_i128();
+    if step > 0 {
+        nanos.div_euclid(step)
+    } else {
+        nanos
+    }
+}
+
+// Inverse of `Timespec::to_nanos_i128`, `None` instead of saturating if
+// the seconds don't fit in an `i64`.
+#[inline]
+fn from_nanos(nanos: i128) -> Option<raw::Timespec> {
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    Some(raw::Timespec::new(
+        secs,
+        nanos.rem_euclid(1_000_000_000) as u32,
+    ))
+}
+
+/// Seconds between 1601-01-01 and 1970-01-01.
+const FILETIME_UNIX_EPOCH_SECS: i64 = 11_644_473_600;
+const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+
 impl Instant {
+    /// Returns an instant corresponding to "now".
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, e.g. when a seccomp filter denies
+    /// `clock_gettime`. See [`Instant::try_now`] for a version without panic.
+    #[must_use]
     #[inline]
     pub fn now() -> Self {
+        match Self::try_now() {
+            Ok(now) => now,
+            Err(errno) => raw::clock_gettime_failed(raw::INSTANT_CLOCKID, errno),
+        }
+    }
+
+    /// Returns an instant corresponding to "now", or the error of
+    /// `clock_gettime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::Instant;
+    ///
+    /// let now = Instant::try_now().unwrap();
+    /// assert!(now <= Instant::now());
+    /// ```
+    #[inline]
+    pub fn try_now() -> Result<Self, Errno> {
+        let t = raw::Timespec::now(raw::INSTANT_CLOCKID)?;
+        #[cfg(feature = "monotonic-guard")]
+        let t = raw::guard_monotonic(t);
+        Ok(Instant { t })
+    }
+
+    /// Returns the resolution of the clock backing `Instant`, as reported by
+    /// `clock_getres(2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clock_getres` fails, which it doesn't for a clock that can
+    /// be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// assert!(Instant::resolution() <= Duration::from_millis(10));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn resolution() -> Duration {
+        raw::Timespec::resolution(raw::INSTANT_CLOCKID).expect("clock_getres failed")
+    }
+
+    /// Returns an instant corresponding to "now" on `clock`.
+    ///
+    /// Fails with `EINVAL` unless `clock` is monotonic, see
+    /// [`ClockId::is_monotonic`](raw::ClockId::is_monotonic). Instants read
+    /// from different clocks can't be compared with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{raw::ClockId, Errno, Instant};
+    ///
+    /// let a = Instant::now_with_clock(ClockId::Monotonic).unwrap();
+    /// let b = Instant::now_with_clock(ClockId::Monotonic).unwrap();
+    /// assert!(b >= a);
+    /// assert_eq!(
+    ///     Instant::now_with_clock(ClockId::Realtime),
+    ///     Err(Errno::EINVAL)
+    /// );
+    /// ```
+    pub fn now_with_clock(clock: raw::ClockId) -> Result<Instant, Errno> {
+        if !clock.is_monotonic() {
+            return Err(Errno::EINVAL);
+        }
+        raw::Timespec::now(clock).map(|t| Instant { t })
+    }
+
+    /// Returns an instant corresponding to "now" on `clock`, e.g. `Boottime`
+    /// for a timeline that keeps counting while the system is suspended.
+    ///
+    /// The instant behaves like any other in arithmetic, but it doesn't
+    /// record its clock: comparing or subtracting instants read from
+    /// different clocks is a logic error, the result is meaningless.
+    ///
+    /// Not even debug builds tag instants with their clock to assert against
+    /// such mixing: the tag would have to be invented for the instants built
+    /// from a [`Timespec`](raw::Timespec) or deserialized, and
+    /// would make `Instant` a different size, and compare differently, in
+    /// debug and release builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clock` isn't monotonic, see
+    /// [`ClockId::is_monotonic`](raw::ClockId::is_monotonic), or can't be
+    /// read. See [`Instant::try_now_with`] for a version without panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{thread::sleep, time::Duration};
+    /// use unix_clock::{raw::ClockId, Instant};
+    ///
+    /// let start = Instant::now_with(ClockId::Monotonic);
+    /// sleep(Duration::from_millis(10));
+    /// assert!(Instant::now_with(ClockId::Monotonic) - start >= Duration::from_millis(10));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn now_with(clock: raw::ClockId) -> Instant {
+        assert!(clock.is_monotonic(), "{:?} is not a monotonic clock", clock);
+        Instant {
+            t: raw::now_or_panic(clock),
+        }
+    }
+
+    /// Returns an instant corresponding to "now" on `clock`, the fallible
+    /// counterpart of [`Instant::now_with`].
+    ///
+    /// Same as [`Instant::now_with_clock`]: fails with `EINVAL` unless `clock`
+    /// is monotonic, or with the error of `clock_gettime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{raw::ClockId, Errno, Instant};
+    ///
+    /// assert!(Instant::try_now_with(ClockId::Monotonic).is_ok());
+    /// assert_eq!(Instant::try_now_with(ClockId::Realtime), Err(Errno::EINVAL));
+    /// ```
+    #[inline]
+    pub fn try_now_with(clock: raw::ClockId) -> Result<Instant, Errno> {
+        Self::now_with_clock(clock)
+    }
+
+    /// Returns an instant corresponding to "now" on the raw hardware clock,
+    /// not slewed by NTP or `adjtime(3)`, e.g. for microbenchmarks.
+    ///
+    /// The clock is `MonotonicRaw` on Linux, which some kernels don't expose
+    /// in the vDSO: reading it then costs a syscall. On Apple platforms it is
+    /// `UptimeRaw`, the clock of [`Instant::now`] already.
+    ///
+    /// Raw instants must only be compared with each other: measured against
+    /// ordinary instants the result is meaningless, see [`Instant::now_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read. See [`Instant::try_now_raw`] for a
+    /// version without panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{thread::sleep, time::Duration};
+    /// use unix_clock::Instant;
+    ///
+    /// let start = Instant::now_raw();
+    /// sleep(Duration::from_millis(10));
+    /// assert!(Instant::now_raw() - start >= Duration::from_millis(9));
+    /// ```
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "watchos",
+        target_os = "tvos"
+    ))]
+    #[must_use]
+    #[inline]
+    pub fn now_raw() -> Instant {
+        Instant {
+            t: raw::now_or_panic(raw::RAW_INSTANT_CLOCKID),
+        }
+    }
+
+    /// Returns an instant corresponding to "now" on the raw hardware clock,
+    /// or the error of `clock_gettime`, see [`Instant::now_raw`].
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "watchos",
+        target_os = "tvos"
+    ))]
+    #[inline]
+    pub fn try_now_raw() -> Result<Instant, Errno> {
+        raw::Timespec::now(raw::RAW_INSTANT_CLOCKID).map(|t| Instant { t })
+    }
+
+    /// Returns an instant corresponding to "now", up to one tick old, for hot
+    /// paths that tolerate a millisecond granularity.
+    ///
+    /// Reads the value cached by the kernel at every tick, typically every 1
+    /// to 10 milliseconds, instead of querying the time counter:
+    /// `MonotonicCoarse` on Linux, FreeBSD and DragonFly, `UptimeRawApprox` on
+    /// Apple platforms. The timeline is the one of [`Instant::now`], so the
+    /// instants can be compared, although a coarse instant can be earlier
+    /// than a precise one read before it. Without a coarse clock, e.g. on
+    /// Linux with the `boottime-instant` feature, this is [`Instant::now`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// let coarse = Instant::now_coarse();
+    /// assert!(Instant::now() - coarse < Duration::from_millis(50));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn now_coarse() -> Instant {
         Instant {
-            t: raw::Timespec::now(raw::INSTANT_CLOCKID).unwrap(),
+            t: raw::now_or_panic(raw::COARSE_INSTANT_CLOCKID),
+        }
+    }
+
+    /// Returns an instant corresponding to "now" along with an estimate of
+    /// its uncertainty.
+    ///
+    /// The clock is read twice in a row: the instant is the midpoint of the
+    /// two readings and the uncertainty half the gap between them, an honest
+    /// error bar for a single timestamp of the same clock. This doubles the
+    /// cost of [`Instant::now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::Instant;
+    ///
+    /// let before = Instant::now();
+    /// let (now, uncertainty) = Instant::now_bounded();
+    /// let after = Instant::now();
+    /// assert!(before <= now && now <= after);
+    /// // both readings fall between `before` and `after`
+    /// assert!(uncertainty <= after - before);
+    /// ```
+    #[must_use]
+    pub fn now_bounded() -> (Instant, Duration) {
+        let first = Instant::now();
+        let second = Instant::now();
+        let half = second.duration_since(first) / 2;
+        (first + half, half)
+    }
+
+    /// Runs `f` and returns its result along with the time it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{thread::sleep, time::Duration};
+    /// use unix_clock::Instant;
+    ///
+    /// let (res, elapsed) = Instant::measure(|| {
+    ///     sleep(Duration::from_millis(10));
+    ///     42
+    /// });
+    /// assert_eq!(res, 42);
+    /// assert!(elapsed >= Duration::from_millis(10));
+    /// ```
+    #[inline]
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+        let start = Instant::now();
+        let res = f();
+        (res, Instant::now().saturating_duration_since(start))
+    }
+
+    /// Awaits `f` and returns its output along with the time it took, from
+    /// the first poll of the returned future to the completion of `f`.
+    ///
+    /// The time includes the intervals in which `f` is pending, not only the
+    /// time spent polling it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{
+    ///     future::Future,
+    ///     pin::Pin,
+    ///     sync::Arc,
+    ///     task::{Context, Poll, Wake},
+    ///     time::Duration,
+    /// };
+    /// use unix_clock::Instant;
+    ///
+    /// struct Noop;
+    ///
+    /// impl Wake for Noop {
+    ///     fn wake(self: Arc<Self>) {}
+    /// }
+    ///
+    /// let mut fut = Box::pin(Instant::measure_async(async {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    ///     42
+    /// }));
+    /// let waker = Arc::new(Noop).into();
+    /// let (res, elapsed) = match fut.as_mut().poll(&mut Context::from_waker(&waker)) {
+    ///     Poll::Ready(out) => out,
+    ///     Poll::Pending => unreachable!(),
+    /// };
+    /// assert_eq!(res, 42);
+    /// assert!(elapsed >= Duration::from_millis(10));
+    /// ```
+    pub async fn measure_async<T>(f: impl core::future::Future<Output = T>) -> (T, Duration) {
+        let start = Instant::now();
+        let res = f.await;
+        (res, Instant::now().saturating_duration_since(start))
+    }
+
+    /// Returns the estimated duration of one call of `f`, averaged over
+    /// `iters` calls and compensated for the cost of reading the clock.
+    ///
+    /// Each call is timed between two [`Instant::now`] reads, so every sample
+    /// also includes the time spent in reading the clock, which dominates
+    /// operations of a few tens of nanoseconds. That overhead is first
+    /// estimated as the average gap between two back-to-back reads, over
+    /// 1024 pairs, then subtracted from every sample.
+    ///
+    /// The result is an estimate: the overhead varies between reads, so the
+    /// compensated time of very fast operations is noisy and saturates at
+    /// zero. Returns zero if `iters` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{thread::sleep, time::Duration};
+    /// use unix_clock::Instant;
+    ///
+    /// // an empty closure is all clock overhead: its estimate is noise
+    /// println!("{:?}", Instant::measure_compensated(10_000, || ()));
+    /// assert_eq!(Instant::measure_compensated(0, || ()), Duration::ZERO);
+    /// assert!(
+    ///     Instant::measure_compensated(3, || sleep(Duration::from_millis(1)))
+    ///         >= Duration::from_millis(1)
+    /// );
+    /// ```
+    pub fn measure_compensated<T>(iters: u64, mut f: impl FnMut() -> T) -> Duration {
+        const CALIBRATION_PAIRS: u32 = 1024;
+
+        if iters == 0 {
+            return Duration::ZERO;
+        }
+
+        let mut overhead = Duration::ZERO;
+        for _ in 0..CALIBRATION_PAIRS {
+            let start = Instant::now();
+            overhead += Instant::now().saturating_duration_since(start);
         }
+        let overhead = overhead / CALIBRATION_PAIRS;
+
+        let mut total: u128 = 0;
+        for _ in 0..iters {
+            let start = Instant::now();
+            drop(f());
+            let elapsed = Instant::now().saturating_duration_since(start);
+            total += elapsed.saturating_sub(overhead).as_nanos();
+        }
+        let nanos = total / iters as u128;
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Creates an `Instant` from a raw timespec.
+    ///
+    /// The timespec is expected to be read from the same clock used by
+    /// [`Instant::now`], otherwise comparing it with other instants is meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{raw::Timespec, Instant};
+    ///
+    /// let future = Instant::from_timespec(Timespec::new(i64::MAX, 999_999_999));
+    /// let past = Instant::from_timespec(Timespec::new(i64::MIN, 0));
+    /// assert_eq!(future.elapsed(), Duration::ZERO);
+    /// assert!(past.elapsed() > Duration::ZERO);
+    /// assert_eq!(past.duration_since(future), Duration::ZERO);
+    /// assert_eq!(
+    ///     future.duration_since(past),
+    ///     Duration::new(u64::MAX, 999_999_999)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_timespec(t: raw::Timespec) -> Self {
+        Self { t }
     }
 
     /// Returns the amount of time elapsed from another instant to this one,
@@ -267,6 +846,70 @@ impl Instant {
         Instant::now() - *self
     }
 
+    /// Converts this instant to a [`std::time::Instant`], for APIs that
+    /// demand one.
+    ///
+    /// `std::time::Instant` is opaque, so the conversion is an estimate: the
+    /// offset of `self` from [`Instant::now`] applied to
+    /// `std::time::Instant::now`. The two reads are not simultaneous, the
+    /// result is off by the time elapsed between them, typically well under
+    /// a microsecond. It is only meaningful if both types read the same
+    /// clock: std's reads `CLOCK_MONOTONIC` on Linux, see
+    /// [`raw::ACTIVE_INSTANT_CLOCK`] for the one of [`Instant`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting point in time cannot be represented by
+    /// `std::time::Instant`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(1);
+    /// let std_deadline = deadline.to_std();
+    /// assert!(std_deadline > std::time::Instant::now());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[track_caller]
+    pub fn to_std(&self) -> std::time::Instant {
+        let now = Instant::now();
+        let std_now = std::time::Instant::now();
+        match self.t.sub_timespec(&now.t) {
+            Ok(ahead) => std_now.checked_add(ahead),
+            Err(behind) => std_now.checked_sub(behind),
+        }
+        .expect("overflow when converting instant to std")
+    }
+
+    /// Returns `true` if `self` has been reached, reading the clock once.
+    ///
+    /// Reads better than `instant <= Instant::now()` when checking expiries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, like [`Instant::now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// let now = Instant::now();
+    /// assert!(now.is_in_past());
+    /// assert!((now - Duration::from_secs(60)).is_in_past());
+    /// assert!(!(now + Duration::from_secs(60)).is_in_past());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_in_past(&self) -> bool {
+        *self <= Instant::now()
+    }
+
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
     /// `Instant` (which means it's inside the bounds of the underlying data structure), `None`
     /// otherwise.
@@ -280,35 +923,195 @@ impl Instant {
     pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
         self.t.checked_sub_duration(&duration).map(|t| Self { t })
     }
-}
 
-impl fmt::Debug for Instant {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Instant")
-            .field("secs", &self.t.secs())
-            .field("nsecs", &self.t.nsecs())
-            .finish()
+    /// Like [`Instant::checked_add`], but reports which bound was exceeded.
+    #[inline]
+    pub fn checked_add_reason(&self, duration: Duration) -> Result<Instant, Overflow> {
+        self.checked_add(duration).ok_or(Overflow { at_max: true })
     }
-}
-
-impl core::ops::Add<Duration> for Instant {
-    type Output = Instant;
 
-    /// # Panics
+    /// Like [`Instant::checked_sub`], but reports which bound was exceeded.
     ///
-    /// This function may panic if the resulting point in time cannot be represented by the
-    /// underlying data structure. See [`Instant::checked_add`] for a version without panic.
-    fn add(self, other: Duration) -> Instant {
-        self.checked_add(other)
-            .expect("overflow when adding duration to instant")
-    }
-}
-
-impl core::ops::AddAssign<Duration> for Instant {
-    fn add_assign(&mut self, other: Duration) {
-        *self = *self + other;
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{raw::Timespec, Instant, Overflow};
+    ///
+    /// let min = Instant::from_timespec(Timespec::MIN);
+    /// assert_eq!(
+    ///     min.checked_sub_reason(Duration::from_nanos(1)),
+    ///     Err(Overflow { at_max: false })
+    /// );
+    /// let max = Instant::from_timespec(Timespec::MAX);
+    /// assert_eq!(
+    ///     max.checked_add_reason(Duration::from_nanos(1)),
+    ///     Err(Overflow { at_max: true })
+    /// );
+    /// assert_eq!(max.checked_sub_reason(Duration::ZERO), Ok(max));
+    /// ```
+    #[inline]
+    pub fn checked_sub_reason(&self, duration: Duration) -> Result<Instant, Overflow> {
+        self.checked_sub(duration).ok_or(Overflow { at_max: false })
     }
-}
+
+    /// Returns the number of whole `tick`s elapsed from `earlier` to `self`,
+    /// or zero if `earlier` is later than `self` or `tick` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// let frame = Duration::from_micros(16_666); // 60fps
+    /// let start = Instant::now();
+    /// assert_eq!((start + Duration::from_secs(1)).ticks_since(start, frame), 60);
+    /// assert_eq!((start + frame * 2).ticks_since(start, frame), 2);
+    /// assert_eq!((start + frame * 2 - Duration::from_nanos(1)).ticks_since(start, frame), 1);
+    /// assert_eq!(start.ticks_since(start + frame, frame), 0);
+    /// assert_eq!(start.ticks_since(start, Duration::ZERO), 0);
+    /// ```
+    #[must_use]
+    pub fn ticks_since(&self, earlier: Instant, tick: Duration) -> u64 {
+        match tick.as_nanos() {
+            0 => 0,
+            tick => {
+                let ticks = self.duration_since(earlier).as_nanos() / tick;
+                if ticks > u64::MAX as u128 {
+                    u64::MAX
+                } else {
+                    ticks as u64
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `self` lies in the half-open range `[start, end)`:
+    /// `start` is included, `end` is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::Instant;
+    ///
+    /// let start = Instant::now();
+    /// let end = start + Duration::from_secs(1);
+    /// assert!(start.in_range(start, end));
+    /// assert!((end - Duration::from_nanos(1)).in_range(start, end));
+    /// assert!(!end.in_range(start, end));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn in_range(&self, start: Instant, end: Instant) -> bool {
+        start <= *self && *self < end
+    }
+
+    /// Returns the earliest instant not before `self` that lies on the grid
+    /// of step `period` shifted by `phase`.
+    ///
+    /// The grid is anchored to the unspecified zero of the monotonic clock,
+    /// so periodic tasks aligning to the same `period` and `phase` tick
+    /// together. A zero `period` returns `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{raw::Timespec, Instant};
+    ///
+    /// let period = Duration::from_millis(100);
+    /// let t = Instant::from_timespec(Timespec::new(10, 123_456_789));
+    /// assert_eq!(
+    ///     t.align_to(period, Duration::ZERO),
+    ///     Instant::from_timespec(Timespec::new(10, 200_000_000))
+    /// );
+    /// assert_eq!(
+    ///     t.align_to(period, Duration::from_millis(30)),
+    ///     Instant::from_timespec(Timespec::new(10, 130_000_000))
+    /// );
+    /// // already on the grid
+    /// let on_grid = Instant::from_timespec(Timespec::new(10, 100_000_000));
+    /// assert_eq!(on_grid.align_to(period, Duration::ZERO), on_grid);
+    /// assert_eq!(t.align_to(Duration::ZERO, Duration::ZERO), t);
+    ///
+    /// let now = Instant::now();
+    /// let next = now.align_to(period, Duration::ZERO);
+    /// assert!(next >= now);
+    /// assert!(next - now < period);
+    /// ```
+    #[must_use]
+    pub fn align_to(&self, period: Duration, phase: Duration) -> Instant {
+        let period = period.as_nanos() as i128;
+        if period == 0 {
+            return *self;
+        }
+
+        let phase = phase.as_nanos() as i128 % period;
+        let nanos = self.t.to_nanos_i128();
+        let mut aligned = (nanos - phase).div_euclid(period) * period + phase;
+        if aligned < nanos {
+            aligned += period;
+        }
+        Instant {
+            t: from_nanos(aligned).expect("overflow when aligning instant"),
+        }
+    }
+
+    /// Feeds the `bucket`-wide time slot `self` falls in into `state`, so
+    /// that every instant of the same slot hashes identically.
+    ///
+    /// See [`SystemTime::hash_bucketed`], slots are aligned to the unspecified
+    /// zero of the monotonic clock.
+    pub fn hash_bucketed<H: core::hash::Hasher>(&self, bucket: Duration, state: &mut H) {
+        use core::hash::Hash;
+
+        self::bucket(&self.t, bucket.as_nanos() as i128).hash(state)
+    }
+}
+
+impl fmt::Debug for Instant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Instant")
+            .field("secs", &self.t.secs())
+            .field("nsecs", &self.t.nsecs())
+            .finish()
+    }
+}
+
+/// Formats the nanoseconds since the zero of the monotonic clock as hex, see
+/// the implementation for [`SystemTime`].
+impl fmt::LowerHex for Instant {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.t.to_nanos_i128(), f)
+    }
+}
+
+impl core::ops::Add<Duration> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure. See [`Instant::checked_add`] for a version without panic.
+    fn add(self, other: Duration) -> Instant {
+        self.checked_add(other)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl core::ops::AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
 
 impl core::ops::Sub<Duration> for Instant {
     type Output = Instant;
@@ -343,6 +1146,19 @@ impl core::ops::Sub<Instant> for Instant {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Instant {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self { t: u.arbitrary()? })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <raw::Timespec as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 impl From<Instant> for raw::Timespec {
     #[inline]
     fn from(value: Instant) -> Self {
@@ -374,6 +1190,29 @@ impl SystemTime {
         t: raw::Timespec::zero(),
     };
 
+    /// The earliest representable system time, [`raw::Timespec::MIN`] away
+    /// from the [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH).
+    pub const MIN: SystemTime = Self {
+        t: raw::Timespec::MIN,
+    };
+
+    /// The latest representable system time, [`raw::Timespec::MAX`] away from
+    /// the [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(SystemTime::MAX.checked_add(Duration::from_nanos(1)), None);
+    /// assert_eq!(SystemTime::MIN.checked_sub(Duration::from_nanos(1)), None);
+    /// assert!(SystemTime::MIN < SystemTime::UNIX_EPOCH && SystemTime::UNIX_EPOCH < SystemTime::MAX);
+    /// ```
+    pub const MAX: SystemTime = Self {
+        t: raw::Timespec::MAX,
+    };
+
     #[inline]
     pub const fn new(sec: i64, nsec: u32) -> Self {
         Self {
@@ -381,8 +1220,220 @@ impl SystemTime {
         }
     }
 
+    /// Creates a `SystemTime` `d` after the [`UNIX_EPOCH`](Self::UNIX_EPOCH),
+    /// rejecting it unless it lies between `min` and `max`, both inclusive.
+    ///
+    /// Meant for timestamps from untrusted input, so garbage values don't
+    /// propagate into logs or databases. A `d` too large to be represented is
+    /// reported as later than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{OutOfRange, SystemTime};
+    ///
+    /// let min = SystemTime::from_civil(2000, 1, 1, 0, 0, 0, 0, 0).unwrap();
+    /// let max = SystemTime::from_civil(2100, 1, 1, 0, 0, 0, 0, 0).unwrap();
+    /// let t = Duration::from_secs(1_700_000_000);
+    /// assert_eq!(
+    ///     SystemTime::from_unix_duration_checked(t, min, max),
+    ///     Ok(SystemTime::UNIX_EPOCH + t)
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::from_unix_duration_checked(Duration::from_secs(42), min, max),
+    ///     Err(OutOfRange { after_max: false })
+    /// );
+    /// ```
+    pub fn from_unix_duration_checked(
+        d: Duration,
+        min: SystemTime,
+        max: SystemTime,
+    ) -> Result<SystemTime, OutOfRange> {
+        match Self::UNIX_EPOCH.checked_add(d) {
+            Some(t) if t < min => Err(OutOfRange { after_max: false }),
+            Some(t) if t <= max => Ok(t),
+            _ => Err(OutOfRange { after_max: true }),
+        }
+    }
+
+    /// Creates a `SystemTime` from broken-down proleptic Gregorian calendar
+    /// components expressed in a timezone `utc_offset_secs` seconds east of UTC.
+    ///
+    /// Returns an [`InvalidDate`] error if any of the components is out of range
+    /// (`month` in `1..=12`, `day` within the month, `hour` in `0..24`, `min` and
+    /// `sec` in `0..60`, `nanos` below one second) or if the offset isn't
+    /// strictly within one day. Leap seconds are not representable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(
+    ///     SystemTime::from_civil(1970, 1, 1, 0, 0, 0, 0, 0),
+    ///     Ok(SystemTime::UNIX_EPOCH)
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::from_civil(2000, 2, 29, 12, 30, 0, 500_000_000, 0),
+    ///     Ok(SystemTime::new(951_827_400, 500_000_000))
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::from_civil(1969, 12, 31, 23, 59, 59, 0, 0),
+    ///     Ok(SystemTime::UNIX_EPOCH - Duration::from_secs(1))
+    /// );
+    /// // 02:00 in UTC+02:00 is midnight UTC
+    /// assert_eq!(
+    ///     SystemTime::from_civil(1970, 1, 1, 2, 0, 0, 0, 7200),
+    ///     Ok(SystemTime::UNIX_EPOCH)
+    /// );
+    /// assert!(SystemTime::from_civil(2024, 13, 1, 0, 0, 0, 0, 0).is_err());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_civil(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        min: u8,
+        sec: u8,
+        nanos: u32,
+        utc_offset_secs: i32,
+    ) -> Result<SystemTime, InvalidDate> {
+        let year = year as i64;
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > civil::days_in_month(year, month)
+            || hour >= 24
+            || min >= 60
+            || sec >= 60
+            || nanos >= 1_000_000_000
+            || (utc_offset_secs as i64).abs() >= civil::SECS_PER_DAY
+        {
+            return Err(InvalidDate(()));
+        }
+
+        let secs = civil::days_from_civil(year, month, day) * civil::SECS_PER_DAY
+            + hour as i64 * 3600
+            + min as i64 * 60
+            + sec as i64
+            - utc_offset_secs as i64;
+        Ok(SystemTime::new(secs, nanos))
+    }
+
+    /// Creates a `SystemTime` from a Windows `FILETIME`, the number of 100
+    /// nanoseconds intervals elapsed since 1601-01-01 00:00:00 UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(
+    ///     SystemTime::from_filetime(116_444_736_000_000_000),
+    ///     SystemTime::UNIX_EPOCH
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::from_filetime(133_485_408_001_234_567),
+    ///     SystemTime::new(1_704_067_200, 123_456_700)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn from_filetime(filetime: u64) -> Self {
+        Self::new(
+            (filetime / FILETIME_TICKS_PER_SEC) as i64 - FILETIME_UNIX_EPOCH_SECS,
+            (filetime % FILETIME_TICKS_PER_SEC) as u32 * 100,
+        )
+    }
+
+    /// Converts this system time to a Windows `FILETIME`, the number of 100
+    /// nanoseconds intervals elapsed since 1601-01-01 00:00:00 UTC.
+    ///
+    /// The sub-interval nanoseconds are truncated, so the conversion rounds
+    /// towards the past. Times before 1601 saturate to `0` and times after
+    /// year 60056 saturate to `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// let now = SystemTime::now();
+    /// let filetime = now.to_filetime();
+    /// assert_eq!(SystemTime::from_filetime(filetime).to_filetime(), filetime);
+    /// assert_eq!(
+    ///     SystemTime::new(1_704_067_200, 123_456_789).to_filetime(),
+    ///     133_485_408_001_234_567
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_filetime(&self) -> u64 {
+        let secs = self.t.secs() as i128 + FILETIME_UNIX_EPOCH_SECS as i128;
+        if secs < 0 {
+            return 0;
+        }
+        let ticks = secs * FILETIME_TICKS_PER_SEC as i128 + (self.t.nsecs() / 100) as i128;
+        if ticks > u64::MAX as i128 {
+            u64::MAX
+        } else {
+            ticks as u64
+        }
+    }
+
+    /// Returns the number of nanoseconds elapsed since the Unix epoch,
+    /// negative before it.
+    ///
+    /// Lossless, see [`raw::Timespec::to_nanos_i128`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(SystemTime::new(1, 500).nanos_since_epoch(), 1_000_000_500);
+    /// assert_eq!(SystemTime::new(-1, 999_999_999).nanos_since_epoch(), -1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn nanos_since_epoch(&self) -> i128 {
+        self.t.to_nanos_i128()
+    }
+
+    /// Creates a system time from a count of nanoseconds since the Unix
+    /// epoch, the inverse of [`SystemTime::nanos_since_epoch`].
+    ///
+    /// Counts beyond the representable range saturate at [`SystemTime::MIN`]
+    /// and [`SystemTime::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// let now = SystemTime::now();
+    /// assert_eq!(SystemTime::from_nanos_since_epoch(now.nanos_since_epoch()), now);
+    /// assert_eq!(
+    ///     SystemTime::from_nanos_since_epoch(-1_000_000_001),
+    ///     SystemTime::new(-2, 999_999_999)
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_nanos_since_epoch(nanos: i128) -> Self {
+        Self {
+            t: raw::Timespec::from_nanos_i128(nanos),
+        }
+    }
+
     /// Returns the system time corresponding to "now".
     ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, e.g. when a seccomp filter denies
+    /// `clock_gettime`. See [`SystemTime::try_now`] for a version without
+    /// panic.
+    ///
     /// # Examples
     ///
     /// ```
@@ -393,8 +1444,221 @@ impl SystemTime {
     #[must_use]
     #[inline]
     pub fn now() -> Self {
-        Self {
-            t: raw::Timespec::now(raw::SYSTEM_TIME_CLOCKID).unwrap(),
+        match Self::try_now() {
+            Ok(now) => now,
+            Err(errno) => raw::clock_gettime_failed(raw::SYSTEM_TIME_CLOCKID, errno),
+        }
+    }
+
+    /// Returns the system time corresponding to "now", or the error of
+    /// `clock_gettime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert!(SystemTime::try_now().unwrap() > SystemTime::UNIX_EPOCH);
+    /// ```
+    #[inline]
+    pub fn try_now() -> Result<Self, Errno> {
+        raw::Timespec::now(raw::SYSTEM_TIME_CLOCKID).map(|t| Self { t })
+    }
+
+    /// Returns the system time corresponding to "now", up to one tick old,
+    /// for hot paths such as timestamping log lines.
+    ///
+    /// Same as [`CoarseWallClock::now`], which documents the clocks read.
+    /// The resolution is the length of a kernel tick, typically 1 to 10
+    /// milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let coarse = SystemTime::now_coarse();
+    /// let lag = SystemTime::now().saturating_duration_since(coarse);
+    /// assert!(lag < Duration::from_millis(50));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn now_coarse() -> Self {
+        CoarseWallClock.now()
+    }
+
+    /// Returns the resolution of the clock backing `SystemTime`, as reported
+    /// by `clock_getres(2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clock_getres` fails, which it doesn't for a clock that can
+    /// be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert!(SystemTime::resolution() <= Duration::from_millis(10));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn resolution() -> Duration {
+        raw::Timespec::resolution(raw::SYSTEM_TIME_CLOCKID).expect("clock_getres failed")
+    }
+
+    /// Returns the system time corresponding to "now" on `clock`.
+    ///
+    /// Fails with `EINVAL` unless `clock` counts the time since the
+    /// [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH), see
+    /// [`ClockId::is_realtime`](raw::ClockId::is_realtime): CPU-time and
+    /// monotonic clocks start from unrelated origins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{raw::ClockId, Errno, SystemTime};
+    ///
+    /// assert!(SystemTime::now_with_clock(ClockId::Realtime).unwrap() > SystemTime::UNIX_EPOCH);
+    /// assert_eq!(
+    ///     SystemTime::now_with_clock(ClockId::ProcessCputimeId),
+    ///     Err(Errno::EINVAL)
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::now_with_clock(ClockId::Monotonic),
+    ///     Err(Errno::EINVAL)
+    /// );
+    /// ```
+    pub fn now_with_clock(clock: raw::ClockId) -> Result<SystemTime, Errno> {
+        if !clock.is_realtime() {
+            return Err(Errno::EINVAL);
+        }
+        raw::Timespec::now(clock).map(|t| SystemTime { t })
+    }
+
+    /// Returns the span from `earlier` to `self` broken into days, hours,
+    /// minutes and seconds, for display in the likes of "3 days, 4 hours ago".
+    ///
+    /// If `earlier` is actually later than `self` the magnitude of the span is
+    /// returned with [`CalendarDuration::negative`] set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{CalendarDuration, SystemTime};
+    ///
+    /// let earlier = SystemTime::new(1_000_000, 0);
+    /// let later = SystemTime::new(1_000_000 + 3 * 86_400 + 4 * 3_600 + 5 * 60 + 6, 7);
+    /// let span = CalendarDuration {
+    ///     negative: false,
+    ///     days: 3,
+    ///     hours: 4,
+    ///     minutes: 5,
+    ///     seconds: 6,
+    ///     nanos: 7,
+    /// };
+    /// assert_eq!(later.calendar_diff(earlier), span);
+    /// assert_eq!(
+    ///     earlier.calendar_diff(later),
+    ///     CalendarDuration {
+    ///         negative: true,
+    ///         ..span
+    ///     }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn calendar_diff(&self, earlier: SystemTime) -> CalendarDuration {
+        match self.duration_since(earlier) {
+            Ok(d) => CalendarDuration::new(d, false),
+            Err(e) => CalendarDuration::new(e.duration(), true),
+        }
+    }
+
+    /// Returns the day of the week of this system time, in UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{SystemTime, Weekday};
+    ///
+    /// assert_eq!(SystemTime::UNIX_EPOCH.weekday(), Weekday::Thursday);
+    /// // 2000-02-29 12:00:00
+    /// assert_eq!(SystemTime::new(951_825_600, 0).weekday(), Weekday::Tuesday);
+    /// // 1969-12-31 23:59:59.5
+    /// assert_eq!(SystemTime::new(-1, 500_000_000).weekday(), Weekday::Wednesday);
+    /// // 1900-01-01
+    /// assert_eq!(SystemTime::new(-2_208_988_800, 0).weekday(), Weekday::Monday);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn weekday(&self) -> Weekday {
+        Weekday::from_days(self.t.secs().div_euclid(civil::SECS_PER_DAY))
+    }
+
+    /// Returns the day of the year of this system time, in UTC, from 1 for
+    /// January 1st to 366 for December 31st of leap years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// assert_eq!(SystemTime::UNIX_EPOCH.day_of_year(), 1);
+    /// // 2000-02-29 12:00:00
+    /// assert_eq!(SystemTime::new(951_825_600, 0).day_of_year(), 60);
+    /// // 2024-12-31 23:59:59
+    /// assert_eq!(SystemTime::new(1_735_689_599, 0).day_of_year(), 366);
+    /// // 1969-12-31 23:59:59.5
+    /// assert_eq!(SystemTime::new(-1, 500_000_000).day_of_year(), 365);
+    /// ```
+    #[must_use]
+    pub const fn day_of_year(&self) -> u16 {
+        let days = self.t.secs().div_euclid(civil::SECS_PER_DAY);
+        let year = civil::year_from_days(days);
+        (days - civil::days_from_civil(year, 1, 1) + 1) as u16
+    }
+
+    /// Returns the signed offset of `self` from `reference`, i.e. `self -
+    /// reference`: positive if `self` is ahead.
+    ///
+    /// This is the quantity a clock synchronization algorithm computes
+    /// between the local clock and a reference time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{SignedDuration, SystemTime};
+    ///
+    /// let reference = SystemTime::new(1_700_000_000, 900_000_000);
+    /// let ahead = SystemTime::new(1_700_000_001, 100_000_000);
+    /// let behind = SystemTime::new(1_700_000_000, 899_999_999);
+    ///
+    /// assert_eq!(reference.offset_from(reference), SignedDuration::ZERO);
+    /// // across a second boundary
+    /// assert_eq!(
+    ///     ahead.offset_from(reference),
+    ///     SignedDuration::new(false, Duration::from_millis(200))
+    /// );
+    /// assert_eq!(
+    ///     reference.offset_from(ahead),
+    ///     SignedDuration::new(true, Duration::from_millis(200))
+    /// );
+    /// // sub-second
+    /// assert_eq!(behind.offset_from(reference).as_nanos(), -1);
+    /// ```
+    #[must_use]
+    pub fn offset_from(&self, reference: SystemTime) -> SignedDuration {
+        match self.t.sub_timespec(&reference.t) {
+            Ok(d) => SignedDuration::new(false, d),
+            Err(d) => SignedDuration::new(true, d),
         }
     }
 
@@ -426,6 +1690,47 @@ impl SystemTime {
         self.t.sub_timespec(&earlier.t).map_err(SystemTimeError)
     }
 
+    /// Returns the amount of time elapsed from an earlier point in time, or
+    /// `None` if `earlier` is later than `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let earlier = SystemTime::new(1_000, 0);
+    /// let later = earlier + Duration::from_secs(5);
+    /// assert_eq!(later.checked_duration_since(earlier), Some(Duration::from_secs(5)));
+    /// assert_eq!(earlier.checked_duration_since(later), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn checked_duration_since(&self, earlier: SystemTime) -> Option<Duration> {
+        self.t.sub_timespec(&earlier.t).ok()
+    }
+
+    /// Returns the amount of time elapsed from an earlier point in time, or
+    /// zero duration if `earlier` is later than `self`, e.g. after the clock
+    /// was set backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let earlier = SystemTime::new(1_000, 0);
+    /// let later = earlier + Duration::from_secs(5);
+    /// assert_eq!(later.saturating_duration_since(earlier), Duration::from_secs(5));
+    /// assert_eq!(earlier.saturating_duration_since(later), Duration::ZERO);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn saturating_duration_since(&self, earlier: SystemTime) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
     /// Returns the difference from this system time to the
     /// current clock time.
     ///
@@ -456,6 +1761,77 @@ impl SystemTime {
         SystemTime::now().duration_since(*self)
     }
 
+    /// Returns the difference from this system time to the current clock
+    /// time, or zero duration if `self` is later than the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, like [`SystemTime::now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let now = SystemTime::now();
+    /// assert!((now - Duration::from_secs(60)).elapsed_or_zero() >= Duration::from_secs(60));
+    /// assert_eq!((now + Duration::from_secs(60)).elapsed_or_zero(), Duration::ZERO);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn elapsed_or_zero(&self) -> Duration {
+        SystemTime::now().saturating_duration_since(*self)
+    }
+
+    /// Returns `true` if `self` is earlier than the current system time,
+    /// reading the clock once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, like [`SystemTime::now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let now = SystemTime::now();
+    /// assert!((now - Duration::from_secs(60)).is_before_now());
+    /// assert!(!(now + Duration::from_secs(60)).is_before_now());
+    /// assert!(SystemTime::UNIX_EPOCH.is_before_now());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_before_now(&self) -> bool {
+        *self < SystemTime::now()
+    }
+
+    /// Returns `true` if `self` is later than the current system time,
+    /// reading the clock once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock can't be read, like [`SystemTime::now`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let now = SystemTime::now();
+    /// assert!((now + Duration::from_secs(60)).is_after_now());
+    /// assert!(!(now - Duration::from_secs(60)).is_after_now());
+    /// assert!(!SystemTime::UNIX_EPOCH.is_after_now());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_after_now(&self) -> bool {
+        *self > SystemTime::now()
+    }
+
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
     /// `SystemTime` (which means it's inside the bounds of the underlying data structure), `None`
     /// otherwise.
@@ -474,6 +1850,185 @@ impl SystemTime {
             .map(|t| SystemTime { t })
     }
 
+    /// Like [`SystemTime::checked_add`], but reports which bound was exceeded.
+    #[inline]
+    pub fn checked_add_reason(&self, duration: Duration) -> Result<SystemTime, Overflow> {
+        self.checked_add(duration).ok_or(Overflow { at_max: true })
+    }
+
+    /// Like [`SystemTime::checked_sub`], but reports which bound was exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{Overflow, SystemTime};
+    ///
+    /// let min = SystemTime::MIN;
+    /// assert_eq!(
+    ///     min.checked_sub_reason(Duration::from_nanos(1)),
+    ///     Err(Overflow { at_max: false })
+    /// );
+    /// assert_eq!(
+    ///     SystemTime::UNIX_EPOCH.checked_sub_reason(Duration::from_secs(1)),
+    ///     Ok(SystemTime::new(-1, 0))
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_sub_reason(&self, duration: Duration) -> Result<SystemTime, Overflow> {
+        self.checked_sub(duration).ok_or(Overflow { at_max: false })
+    }
+
+    /// Returns `true` if `self` lies in the half-open range `[start, end)`:
+    /// `start` is included, `end` is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::SystemTime;
+    ///
+    /// let start = SystemTime::new(1_000, 0);
+    /// let end = SystemTime::new(1_060, 0);
+    /// assert!(start.in_range(start, end));
+    /// assert!(SystemTime::new(1_059, 999_999_999).in_range(start, end));
+    /// assert!(!end.in_range(start, end));
+    /// assert!(!SystemTime::new(999, 999_999_999).in_range(start, end));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn in_range(&self, start: SystemTime, end: SystemTime) -> bool {
+        start <= *self && *self < end
+    }
+
+    /// Returns `true` if `self` and `other` fall in the same tick of `clock`,
+    /// i.e. if they are indistinguishable at its resolution.
+    ///
+    /// Both times are truncated to a multiple of the resolution reported by
+    /// `clock_getres(2)` before being compared. This is the comparison to use
+    /// between a timestamp read from a coarse clock and one read from a
+    /// precise clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::{raw::{ClockId, Timespec}, SystemTime};
+    ///
+    /// let res = Timespec::resolution(ClockId::Realtime).unwrap();
+    ///
+    /// let t = SystemTime::UNIX_EPOCH;
+    /// assert!(t.eq_at_resolution(t + res / 2, ClockId::Realtime).unwrap());
+    /// assert!(!t.eq_at_resolution(t + res * 2, ClockId::Realtime).unwrap());
+    /// ```
+    pub fn eq_at_resolution(&self, other: SystemTime, clock: raw::ClockId) -> Result<bool, Errno> {
+        let res = raw::Timespec::resolution(clock)?.as_nanos() as i128;
+        Ok(bucket(&self.t, res) == bucket(&other.t, res))
+    }
+
+    /// Feeds the `bucket`-wide time slot `self` falls in into `state`, so
+    /// that every timestamp of the same slot hashes identically.
+    ///
+    /// Slots are aligned to the [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH), a
+    /// zero `bucket` hashes the exact timestamp. This allows deduplicating
+    /// events per time window, e.g. one per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{collections::hash_map::DefaultHasher, hash::Hasher, time::Duration};
+    /// use unix_clock::SystemTime;
+    ///
+    /// let hash = |t: SystemTime| {
+    ///     let mut state = DefaultHasher::new();
+    ///     t.hash_bucketed(Duration::from_secs(1), &mut state);
+    ///     state.finish()
+    /// };
+    ///
+    /// // same bucket
+    /// assert_eq!(hash(SystemTime::new(100, 1)), hash(SystemTime::new(100, 999_999_999)));
+    /// // across a boundary
+    /// assert_ne!(hash(SystemTime::new(100, 999_999_999)), hash(SystemTime::new(101, 0)));
+    /// // before the epoch
+    /// assert_eq!(hash(SystemTime::new(-1, 0)), hash(SystemTime::new(-1, 500_000_000)));
+    /// assert_ne!(hash(SystemTime::new(-1, 0)), hash(SystemTime::new(0, 0)));
+    /// ```
+    pub fn hash_bucketed<H: core::hash::Hasher>(&self, bucket: Duration, state: &mut H) {
+        use core::hash::Hash;
+
+        self::bucket(&self.t, bucket.as_nanos() as i128).hash(state)
+    }
+
+    /// Returns the latest multiple of `grid` since the
+    /// [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH) that is not after `self`, e.g.
+    /// the start of the metrics window `self` falls in.
+    ///
+    /// Times before the epoch are floored too, towards the past. A zero `grid`
+    /// returns `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let grid = Duration::from_secs(10);
+    /// assert_eq!(SystemTime::new(1_234, 500).floor_to(grid), SystemTime::new(1_230, 0));
+    /// assert_eq!(SystemTime::new(1_230, 0).floor_to(grid), SystemTime::new(1_230, 0));
+    /// assert_eq!(SystemTime::new(-1, 0).floor_to(grid), SystemTime::new(-10, 0));
+    /// ```
+    #[must_use]
+    pub fn floor_to(&self, grid: Duration) -> SystemTime {
+        self.snap_to(grid, false)
+    }
+
+    /// Returns the earliest multiple of `grid` since the
+    /// [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH) that is not before `self`, e.g.
+    /// the end of the metrics window `self` falls in.
+    ///
+    /// Times before the epoch are ceiled too, towards the epoch. A zero `grid`
+    /// returns `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let grid = Duration::from_secs(10);
+    /// assert_eq!(SystemTime::new(1_234, 500).ceil_to(grid), SystemTime::new(1_240, 0));
+    /// assert_eq!(SystemTime::new(1_230, 0).ceil_to(grid), SystemTime::new(1_230, 0));
+    /// assert_eq!(SystemTime::new(-11, 0).ceil_to(grid), SystemTime::new(-10, 0));
+    /// ```
+    #[must_use]
+    pub fn ceil_to(&self, grid: Duration) -> SystemTime {
+        self.snap_to(grid, true)
+    }
+
+    fn snap_to(&self, grid: Duration, ceil: bool) -> SystemTime {
+        let grid = grid.as_nanos() as i128;
+        if grid == 0 {
+            return *self;
+        }
+
+        let nanos = self.t.to_nanos_i128();
+        let mut snapped = bucket(&self.t, grid) * grid;
+        if ceil && snapped < nanos {
+            snapped += grid;
+        }
+        SystemTime {
+            t: from_nanos(snapped).expect("overflow when aligning system time"),
+        }
+    }
+
     #[inline]
     pub fn set_clock(&self) -> Result<(), Errno> {
         self.t.set_clock()
@@ -514,6 +2069,109 @@ impl core::ops::SubAssign<Duration> for SystemTime {
     }
 }
 
+impl SystemTimeError {
+    /// Returns the positive duration which represents how far forward the
+    /// second system time was from the first.
+    ///
+    /// A `SystemTimeError` is returned from the [`SystemTime::duration_since`]
+    /// and [`SystemTime::elapsed`] methods whenever the second system time
+    /// represents a point later in time than the `self` of the method call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::thread::sleep;
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let sys_time = SystemTime::now();
+    /// sleep(Duration::from_secs(1));
+    /// let new_sys_time = SystemTime::now();
+    /// match sys_time.duration_since(new_sys_time) {
+    ///     Ok(_) => {}
+    ///     Err(e) => println!("SystemTimeError difference: {:?}", e.duration()),
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
+
+    /// Reconstructs the later system time that caused this error, given the
+    /// `self` of the failed [`SystemTime::duration_since`] call as `reference`.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let earlier = SystemTime::new(1_000, 0);
+    /// let later = SystemTime::new(1_005, 250_000_000);
+    /// let err = earlier.duration_since(later).unwrap_err();
+    /// assert_eq!(err.duration(), Duration::new(5, 250_000_000));
+    /// assert_eq!(err.later_time(earlier), later);
+    /// ```
+    #[must_use]
+    pub fn later_time(&self, reference: SystemTime) -> SystemTime {
+        reference + self.0
+    }
+}
+
+impl fmt::Display for SystemTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "second time provided was later than self by {:?}",
+            self.0
+        )
+    }
+}
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid calendar date")
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.after_max {
+            f.write_str("timestamp later than the accepted range")
+        } else {
+            f.write_str("timestamp earlier than the accepted range")
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.at_max {
+            f.write_str("overflow past the latest representable point in time")
+        } else {
+            f.write_str("overflow past the earliest representable point in time")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SystemTimeError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDate {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Overflow {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
 impl fmt::Debug for SystemTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SystemTime")
@@ -523,9 +2181,120 @@ impl fmt::Debug for SystemTime {
     }
 }
 
+/// Formats the nanoseconds since the [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH)
+/// as hex, e.g. to embed a timestamp in a trace id.
+///
+/// The count is an `i128`, formatted like one: times before the epoch come
+/// out in two's complement, 32 hex digits starting with `f`. The formatting
+/// flags apply as for integers.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::SystemTime;
+///
+/// let t = SystemTime::new(1_700_000_000, 123_456_789);
+/// assert_eq!(format!("{:x}", t), "17979cfe3d85cd15");
+/// assert_eq!(format!("{:#020x}", t), "0x0017979cfe3d85cd15");
+/// assert_eq!(
+///     format!("{:x}", SystemTime::new(-1, 999_999_999)),
+///     "ffffffffffffffffffffffffffffffff"
+/// );
+/// ```
+impl fmt::LowerHex for SystemTime {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.t.to_nanos_i128(), f)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SystemTime {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self { t: u.arbitrary()? })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <raw::Timespec as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+impl From<raw::Timespec> for SystemTime {
+    #[inline]
+    fn from(value: raw::Timespec) -> Self {
+        Self { t: value }
+    }
+}
+
 impl From<SystemTime> for raw::Timespec {
     #[inline]
     fn from(value: SystemTime) -> Self {
         value.t
     }
 }
+
+#[cfg(feature = "std")]
+impl From<SystemTime> for std::time::SystemTime {
+    /// Converts to the standard library's system time.
+    ///
+    /// Both sides go through their distance from the epoch. On Unix
+    /// `std::time::SystemTime` stores 64-bit seconds too, so every value
+    /// converts in practice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std::time::SystemTime` can't represent `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let t = SystemTime::new(-1, 500_000_000);
+    /// assert_eq!(
+    ///     std::time::SystemTime::from(t),
+    ///     std::time::SystemTime::UNIX_EPOCH - Duration::from_millis(500)
+    /// );
+    /// ```
+    #[track_caller]
+    fn from(value: SystemTime) -> Self {
+        match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(d) => std::time::SystemTime::UNIX_EPOCH.checked_add(d),
+            Err(e) => std::time::SystemTime::UNIX_EPOCH.checked_sub(e.duration()),
+        }
+        .expect("overflow when converting system time to std")
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for SystemTime {
+    type Error = Overflow;
+
+    /// Converts from the standard library's system time, failing if `value`
+    /// is more than `i64::MAX` seconds away from the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::SystemTime;
+    ///
+    /// let t = std::time::SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5);
+    /// let converted = SystemTime::try_from(t).unwrap();
+    /// assert_eq!(converted, SystemTime::new(1_700_000_000, 5));
+    /// assert_eq!(std::time::SystemTime::from(converted), t);
+    /// ```
+    fn try_from(value: std::time::SystemTime) -> Result<Self, Self::Error> {
+        match value.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(d) => SystemTime::UNIX_EPOCH
+                .checked_add(d)
+                .ok_or(Overflow { at_max: true }),
+            Err(e) => SystemTime::UNIX_EPOCH
+                .checked_sub(e.duration())
+                .ok_or(Overflow { at_max: false }),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+
+use crate::Instant;
+
+/// A map evicting its entries both when they expire and, once it is full, in
+/// least recently used order.
+///
+/// Every entry expires `ttl` after its insertion; expired entries are never
+/// returned and are dropped lazily, when looked up or when room is needed.
+/// Inserting into a full map first drops the expired entries and, only if it
+/// is still full, the least recently inserted or looked up one.
+///
+/// The methods ending in `_at` take the current time as a parameter, which
+/// allows driving the map with a virtual clock.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Instant, TimedLru};
+///
+/// let start = Instant::now();
+/// let mut cache = TimedLru::new(2, Duration::from_secs(60));
+/// cache.insert_at(start, "a", 1);
+/// cache.insert_at(start, "b", 2);
+/// assert_eq!(cache.get_at(start, &"a"), Some(&1));
+///
+/// // full: "b" is the least recently used
+/// cache.insert_at(start, "c", 3);
+/// assert_eq!(cache.get_at(start, &"b"), None);
+///
+/// // a minute later everything expired
+/// let later = start + Duration::from_secs(60);
+/// assert_eq!(cache.get_at(later, &"a"), None);
+/// assert_eq!(cache.get_at(later, &"c"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimedLru<K, V> {
+    capacity: usize,
+    ttl: Duration,
+    entries: BTreeMap<K, Entry<V>>,
+    // Keys by last use, least recent first.
+    order: BTreeMap<u64, K>,
+    next_use: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<V> {
+    value: V,
+    // `None` if the expiration isn't representable, i.e. never.
+    expires_at: Option<Instant>,
+    last_use: u64,
+}
+
+impl<V> Entry<V> {
+    #[inline]
+    fn is_expired(&self, now: Instant) -> bool {
+        matches!(self.expires_at, Some(at) if now >= at)
+    }
+}
+
+impl<K: Ord + Clone, V> TimedLru<K, V> {
+    /// Creates an empty map holding up to `capacity` entries, each for `ttl`
+    /// after its insertion.
+    #[inline]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: BTreeMap::new(),
+            order: BTreeMap::new(),
+            next_use: 0,
+        }
+    }
+
+    /// Returns the maximum number of entries.
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns how long entries live after their insertion.
+    #[must_use]
+    #[inline]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the number of entries, expired ones not dropped yet included.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no entry, expired or not.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts `value` under `key` now, see [`TimedLru::insert_at`].
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_at(Instant::now(), key, value)
+    }
+
+    /// Inserts `value` under `key` at `now`, making room if the map is full.
+    ///
+    /// Returns the value previously stored under `key`, if it hadn't expired
+    /// yet. The expiration of the entry restarts from `now`.
+    pub fn insert_at(&mut self, now: Instant, key: K, value: V) -> Option<V> {
+        let previous = self.take(&key).filter(|e| !e.is_expired(now));
+        if self.capacity == 0 {
+            return previous.map(|e| e.value);
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.purge_expired_at(now);
+        }
+        while self.entries.len() >= self.capacity {
+            let lru = match self.order.iter().next() {
+                Some((_, key)) => key.clone(),
+                None => break,
+            };
+            self.take(&lru);
+        }
+
+        let last_use = self.bump();
+        self.order.insert(last_use, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                expires_at: now.checked_add(self.ttl),
+                last_use,
+            },
+        );
+        previous.map(|e| e.value)
+    }
+
+    /// Looks `key` up now, see [`TimedLru::get_at`].
+    #[inline]
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.get_at(Instant::now(), key)
+    }
+
+    /// Returns the value stored under `key` unless it expired by `now`,
+    /// marking it as the most recently used.
+    pub fn get_at(&mut self, now: Instant, key: &K) -> Option<&V> {
+        match self.entries.get(key) {
+            Some(entry) if entry.is_expired(now) => {
+                self.take(key);
+                return None;
+            }
+            Some(_) => (),
+            None => return None,
+        }
+
+        let last_use = self.bump();
+        let entry = self.entries.get_mut(key)?;
+        self.order.remove(&entry.last_use);
+        self.order.insert(last_use, key.clone());
+        entry.last_use = last_use;
+        Some(&entry.value)
+    }
+
+    /// Removes the entry of `key` now, see [`TimedLru::remove_at`].
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove_at(Instant::now(), key)
+    }
+
+    /// Removes the entry of `key`, returning its value unless it expired by
+    /// `now`.
+    pub fn remove_at(&mut self, now: Instant, key: &K) -> Option<V> {
+        self.take(key)
+            .filter(|e| !e.is_expired(now))
+            .map(|e| e.value)
+    }
+
+    /// Drops every entry expired by `now`.
+    pub fn purge_expired_at(&mut self, now: Instant) {
+        let entries = &mut self.entries;
+        entries.retain(|_, entry| !entry.is_expired(now));
+        self.order.retain(|_, key| entries.contains_key(key));
+    }
+
+    #[inline]
+    fn take(&mut self, key: &K) -> Option<Entry<V>> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.last_use);
+        Some(entry)
+    }
+
+    #[inline]
+    fn bump(&mut self) -> u64 {
+        let last_use = self.next_use;
+        self.next_use += 1;
+        last_use
+    }
+}
@@ -0,0 +1,166 @@
+use core::time::Duration;
+
+use crate::{raw, Instant, SignedDuration};
+
+// Share of the phase error slewed away over the next interval, as a shift:
+// half.
+const PHASE_GAIN_SHIFT: u32 = 1;
+// Share of the frequency error folded into the rate at each observation, as
+// a shift: an eighth. With the phase gain above the loop settles within a
+// few tens of observations, without oscillating much.
+const FREQ_GAIN_SHIFT: u32 = 3;
+// Slews beyond ±500ppm are not clock drift, as in NTP.
+const MAX_RATE_PPB: i64 = 500_000;
+const PPB: i128 = 1_000_000_000;
+
+/// A clock following a drifting reference, e.g. the media clock of a stream,
+/// by slewing the local monotonic clock.
+///
+/// Each [`observe`](PhaseLockedClock::observe) compares the reference with
+/// the adjusted clock and, like a phase-locked loop, folds the frequency
+/// error into a rate multiplier and slews part of the phase error away over
+/// the next interval. The adjusted clock never jumps after the first
+/// observation, it only runs faster or slower than [`Instant`], by at most
+/// 500ppm: like [`Instant`], it never goes backwards.
+///
+/// The rate is kept in integer parts per billion, so the adjusted time
+/// doesn't accumulate floating point rounding.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Instant, PhaseLockedClock};
+///
+/// // the source runs 100ppm fast
+/// let start = Instant::now();
+/// let source = |local: Instant| start + (local - start) * 10_001 / 10_000;
+///
+/// let mut clock = PhaseLockedClock::new_at(start);
+/// let mut local = start;
+/// let mut errors = Vec::new();
+/// for _ in 0..100 {
+///     local += Duration::from_millis(100);
+///     errors.push(clock.observe_at(local, source(local)).unsigned_abs());
+/// }
+/// assert!(errors[10] < errors[2]);
+/// assert!(errors[99] < Duration::from_micros(1));
+/// assert!((clock.rate_ppb() - 100_000).abs() < 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhaseLockedClock {
+    anchor_local: Instant,
+    anchor_adjusted: i128,
+    rate_ppb: i64,
+    // Extra rate correcting the phase until the next observation.
+    slew_ppb: i64,
+    locked: bool,
+}
+
+impl PhaseLockedClock {
+    /// Creates a clock running at the pace of [`Instant`] from now, until
+    /// the first observation.
+    #[inline]
+    pub fn new() -> Self {
+        Self::new_at(Instant::now())
+    }
+
+    /// Creates a clock reading `local` at `local`.
+    #[inline]
+    pub const fn new_at(local: Instant) -> Self {
+        Self {
+            anchor_local: local,
+            anchor_adjusted: local.t.to_nanos_i128(),
+            rate_ppb: 0,
+            slew_ppb: 0,
+            locked: false,
+        }
+    }
+
+    /// Returns the adjusted time corresponding to "now".
+    #[must_use]
+    #[inline]
+    pub fn now_adjusted(&self) -> Instant {
+        self.adjusted_at(Instant::now())
+    }
+
+    /// Returns the adjusted time at the local instant `local`.
+    #[must_use]
+    pub fn adjusted_at(&self, local: Instant) -> Instant {
+        Instant {
+            t: raw::Timespec::from_nanos_i128(self.adjusted_nanos(local)),
+        }
+    }
+
+    /// Returns how much faster than [`Instant`] the adjusted clock runs, in
+    /// parts per billion.
+    #[must_use]
+    #[inline]
+    pub const fn rate_ppb(&self) -> i64 {
+        self.rate_ppb
+    }
+
+    /// Feeds a reading of the reference clock taken now.
+    ///
+    /// See [`PhaseLockedClock::observe_at`].
+    #[inline]
+    pub fn observe(&mut self, reference: Instant) -> SignedDuration {
+        self.observe_at(Instant::now(), reference)
+    }
+
+    /// Feeds a reading `reference` of the reference clock taken at the local
+    /// instant `local`, returning the phase error, i.e. how far the reference
+    /// was ahead of the adjusted clock.
+    ///
+    /// The first observation steps the adjusted clock onto the reference.
+    /// Observations not later than the previous one are only measured.
+    pub fn observe_at(&mut self, local: Instant, reference: Instant) -> SignedDuration {
+        let adjusted = self.adjusted_nanos(local);
+        let error = reference.t.to_nanos_i128() - adjusted;
+        let offset = SignedDuration::new(
+            error < 0,
+            Duration::new(
+                (error.unsigned_abs() / PPB as u128) as u64,
+                (error.unsigned_abs() % PPB as u128) as u32,
+            ),
+        );
+
+        if !self.locked {
+            self.locked = true;
+            self.anchor_local = local;
+            self.anchor_adjusted = reference.t.to_nanos_i128();
+            return offset;
+        }
+
+        let dt = local.t.to_nanos_i128() - self.anchor_local.t.to_nanos_i128();
+        if dt <= 0 {
+            return offset;
+        }
+        let max = MAX_RATE_PPB as i128;
+        let freq_error = error * PPB / dt;
+        let rate = (self.rate_ppb as i128 + (freq_error >> FREQ_GAIN_SHIFT)).clamp(-max, max);
+        // Assuming the next interval lasts as long as this one, and keeping
+        // the total within bounds.
+        let slew = ((error >> PHASE_GAIN_SHIFT) * PPB / dt).clamp(-max - rate, max - rate);
+        self.rate_ppb = rate as i64;
+        self.slew_ppb = slew as i64;
+        // Re-anchored where the clock is: the adjusted time stays continuous.
+        self.anchor_local = local;
+        self.anchor_adjusted = adjusted;
+        offset
+    }
+
+    #[inline]
+    fn adjusted_nanos(&self, local: Instant) -> i128 {
+        let elapsed = local.t.to_nanos_i128() - self.anchor_local.t.to_nanos_i128();
+        let rate = PPB + self.rate_ppb as i128 + self.slew_ppb as i128;
+        self.anchor_adjusted + elapsed * rate / PPB
+    }
+}
+
+impl Default for PhaseLockedClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
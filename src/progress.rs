@@ -0,0 +1,100 @@
+use core::time::Duration;
+
+use crate::{raw, Instant};
+
+const SAMPLES: usize = 16;
+
+/// Estimates the remaining time of a task from its recent progress, as
+/// shown by progress bars.
+///
+/// The rate is measured over the samples recorded in the last `window`, up
+/// to the latest 16: old bursts or stalls stop affecting the estimate once
+/// they leave the window.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Instant, SmoothedElapsed};
+///
+/// let start = Instant::now();
+/// let mut eta = SmoothedElapsed::new(1_000, Duration::from_secs(1));
+/// assert_eq!(eta.eta(), None);
+///
+/// // 10 units every 100ms
+/// for i in 0..=50 {
+///     eta.record_at(start + Duration::from_millis(100) * i, 10 * i as u64);
+/// }
+/// assert_eq!(eta.eta(), Some(Duration::from_secs(5)));
+///
+/// // stalled for longer than the window
+/// let stalled = start + Duration::from_millis(100) * 50;
+/// for i in 1..=20 {
+///     eta.record_at(stalled + Duration::from_millis(100) * i, 500);
+/// }
+/// assert_eq!(eta.eta(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SmoothedElapsed {
+    total: u64,
+    window: Duration,
+    samples: [(Instant, u64); SAMPLES],
+    len: usize,
+    next: usize,
+}
+
+impl SmoothedElapsed {
+    /// Creates an estimator for a task made of `total` units, measuring its
+    /// rate over the last `window`.
+    #[inline]
+    pub const fn new(total: u64, window: Duration) -> Self {
+        Self {
+            total,
+            window,
+            samples: [(Instant::from_timespec(raw::Timespec::zero()), 0); SAMPLES],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Records that `progress` units out of the total are done by now.
+    #[inline]
+    pub fn record(&mut self, progress: u64) {
+        self.record_at(Instant::now(), progress)
+    }
+
+    /// Records that `progress` units out of the total are done at `now`.
+    pub fn record_at(&mut self, now: Instant, progress: u64) {
+        self.samples[self.next] = (now, progress);
+        self.next = (self.next + 1) % SAMPLES;
+        self.len = (self.len + 1).min(SAMPLES);
+    }
+
+    /// Returns the estimated time left from the latest sample to the
+    /// completion of the task.
+    ///
+    /// Returns `None` until two samples have been recorded in the window, and
+    /// if no progress has been made over it.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.len == 0 {
+            return None;
+        }
+        let (last_at, last) = self.samples[(self.next + SAMPLES - 1) % SAMPLES];
+        if last >= self.total {
+            return Some(Duration::ZERO);
+        }
+
+        let (first_at, first) = (1..=self.len)
+            .rev()
+            .map(|age| self.samples[(self.next + SAMPLES - age) % SAMPLES])
+            .find(|&(at, _)| last_at.saturating_duration_since(at) <= self.window)?;
+        let progress = last.checked_sub(first).filter(|&p| p != 0)?;
+
+        let elapsed = last_at.saturating_duration_since(first_at).as_nanos();
+        let left = elapsed.checked_mul((self.total - last) as u128)? / progress as u128;
+        Some(Duration::new(
+            u64::try_from(left / 1_000_000_000).ok()?,
+            (left % 1_000_000_000) as u32,
+        ))
+    }
+}
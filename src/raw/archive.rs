@@ -0,0 +1,118 @@
+use rend::{i64_le, u32_le};
+use rkyv::{Archive, Archived, Deserialize, Fallible, Serialize};
+
+use super::Timespec;
+use crate::SystemTime;
+
+/// The archived form of [`Timespec`] and [`SystemTime`].
+///
+/// It is the little-endian `(secs, nsecs)` pair, followed by four zero bytes
+/// of padding, on every platform: archives can be shared between hosts no
+/// matter the architecture or the in-memory `Timespec` layout.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::Deserialize;
+/// use unix_clock::SystemTime;
+///
+/// let t = SystemTime::new(1_700_000_000, 123);
+/// let bytes = rkyv::to_bytes::<_, 64>(&t).unwrap();
+/// assert_eq!(
+///     &bytes[..],
+///     &[0, 241, 83, 101, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0]
+/// );
+///
+/// let archived = unsafe { rkyv::archived_root::<SystemTime>(&bytes) };
+/// assert_eq!(archived.secs(), 1_700_000_000);
+/// assert_eq!(archived.nsecs(), 123);
+///
+/// let restored: SystemTime = archived.deserialize(&mut rkyv::Infallible).unwrap();
+/// assert_eq!(restored, t);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct ArchivedTimespec {
+    secs: i64_le,
+    nsecs: u32_le,
+    padding: u32_le,
+}
+
+impl ArchivedTimespec {
+    #[inline]
+    fn new(t: &Timespec) -> Self {
+        Self {
+            secs: i64_le::new(t.secs()),
+            nsecs: u32_le::new(t.nsecs()),
+            padding: u32_le::new(0),
+        }
+    }
+
+    /// Returns the archived seconds.
+    #[inline]
+    pub fn secs(&self) -> i64 {
+        self.secs.value()
+    }
+
+    /// Returns the archived nanoseconds.
+    #[inline]
+    pub fn nsecs(&self) -> u32 {
+        self.nsecs.value()
+    }
+
+    /// Returns the archived time as a [`Timespec`], without deserialization.
+    #[inline]
+    pub fn to_timespec(&self) -> Timespec {
+        Timespec::new(self.secs(), self.nsecs())
+    }
+}
+
+impl Archive for Timespec {
+    type Archived = ArchivedTimespec;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        out.write(ArchivedTimespec::new(self));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Timespec {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Timespec, D> for Archived<Timespec> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Timespec, D::Error> {
+        Ok(self.to_timespec())
+    }
+}
+
+impl Archive for SystemTime {
+    type Archived = ArchivedTimespec;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        out.write(ArchivedTimespec::new(&self.t));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for SystemTime {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<SystemTime, D> for Archived<SystemTime> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<SystemTime, D::Error> {
+        Ok(SystemTime {
+            t: self.to_timespec(),
+        })
+    }
+}
@@ -0,0 +1,158 @@
+use core::{fmt, str::FromStr};
+
+use super::ClockId;
+
+/// An error returned when converting an integer or a name to a [`ClockId`].
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{ClockId, ClockIdError};
+///
+/// assert_eq!(ClockId::try_from(i32::from(ClockId::Monotonic)), Ok(ClockId::Monotonic));
+/// assert_eq!(ClockId::try_from(-1i32), Err(ClockIdError::Unknown));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockIdError {
+    /// The value isn't the id or the name of any clock of the platform.
+    Unknown,
+    /// The clock exists on the platform, but the running kernel doesn't
+    /// implement it.
+    Unsupported(ClockId),
+}
+
+impl fmt::Display for ClockIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown clock id"),
+            Self::Unsupported(clock) => write!(f, "clock {:?} not supported by the kernel", clock),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClockIdError {}
+
+#[inline]
+fn is_supported(clock: ClockId) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        clock.is_supported()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        super::Timespec::resolution(clock).is_ok()
+    }
+}
+
+/// Maps a raw clock id back to the enum, probing with `clock_getres(2)` that
+/// the running kernel implements it.
+impl TryFrom<i32> for ClockId {
+    type Error = ClockIdError;
+
+    fn try_from(id: i32) -> Result<Self, Self::Error> {
+        let clock = Self::VARIANTS
+            .into_iter()
+            .find(|&clock| i32::from(clock) == id)
+            .ok_or(ClockIdError::Unknown)?;
+        if is_supported(clock) {
+            Ok(clock)
+        } else {
+            Err(ClockIdError::Unsupported(clock))
+        }
+    }
+}
+
+/// Like the conversion from `i32`, for platforms and protocols carrying
+/// clock ids unsigned.
+impl TryFrom<u32> for ClockId {
+    type Error = ClockIdError;
+
+    #[inline]
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        i32::try_from(id)
+            .map_err(|_| ClockIdError::Unknown)
+            .and_then(Self::try_from)
+    }
+}
+
+impl From<ClockId> for i32 {
+    #[inline]
+    fn from(clock: ClockId) -> Self {
+        // Every clock id of every platform is a small non-negative integer.
+        clock as i32
+    }
+}
+
+impl From<ClockId> for u32 {
+    #[inline]
+    fn from(clock: ClockId) -> Self {
+        clock as u32
+    }
+}
+
+impl fmt::Display for ClockId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+// Compares the text written to it with `expected`, ignoring ASCII case.
+struct EqIgnoreCase<'a> {
+    expected: &'a [u8],
+    matches: bool,
+}
+
+impl fmt::Write for EqIgnoreCase<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.expected.get(..s.len()) {
+            Some(head) if self.matches && head.eq_ignore_ascii_case(s.as_bytes()) => {
+                self.expected = &self.expected[s.len()..];
+            }
+            _ => self.matches = false,
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn variant_name_eq(clock: ClockId, name: &str) -> bool {
+    use fmt::Write;
+
+    let mut cmp = EqIgnoreCase {
+        expected: name.as_bytes(),
+        matches: true,
+    };
+    let _ = write!(cmp, "{:?}", clock);
+    cmp.matches && cmp.expected.is_empty()
+}
+
+/// Parses the name of a clock of the platform, either the C constant, e.g.
+/// `CLOCK_MONOTONIC`, or the variant, e.g. `Monotonic`, ignoring case.
+///
+/// Unlike the conversion from integers, the running kernel isn't probed.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{ClockId, ClockIdError};
+///
+/// assert_eq!("CLOCK_MONOTONIC".parse(), Ok(ClockId::Monotonic));
+/// assert_eq!("clock_monotonic".parse(), Ok(ClockId::Monotonic));
+/// assert_eq!("ProcessCputimeId".parse(), Ok(ClockId::ProcessCputimeId));
+/// assert_eq!("CLOCK_BOGUS".parse::<ClockId>(), Err(ClockIdError::Unknown));
+///
+/// let clock = ClockId::Realtime;
+/// assert_eq!(clock.to_string().parse(), Ok(clock));
+/// ```
+impl FromStr for ClockId {
+    type Err = ClockIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .into_iter()
+            .find(|&clock| clock.name().eq_ignore_ascii_case(s) || variant_name_eq(clock, s))
+            .ok_or(ClockIdError::Unknown)
+    }
+}
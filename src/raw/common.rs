@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
 
 use crate::Errno;
 
@@ -61,6 +61,75 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+impl ClockId {
+    // Every variant, to map raw ids back to the enum.
+    pub(crate) const VARIANTS: [Self; 8] = [
+        Self::Realtime,
+        Self::MonotonicRaw,
+        Self::MonotonicRawApprox,
+        Self::Monotonic,
+        Self::UptimeRaw,
+        Self::UptimeRawApprox,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` for the clocks counting the wall-clock time since the
+    /// Unix epoch, see [`SystemTime::now_with_clock`](crate::SystemTime::now_with_clock).
+    #[inline]
+    pub const fn is_realtime(&self) -> bool {
+        matches!(self, Self::Realtime)
+    }
+
+    /// Returns `true` for the clocks that never go backwards and tick at the
+    /// pace of the wall-clock time, see
+    /// [`Instant::now_with_clock`](crate::Instant::now_with_clock).
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::Monotonic
+                | Self::MonotonicRaw
+                | Self::MonotonicRawApprox
+                | Self::UptimeRaw
+                | Self::UptimeRawApprox
+        )
+    }
+
+    /// Returns `true` for the clocks reading a value cached by the kernel,
+    /// e.g. at the last timer tick, instead of querying the time counter.
+    ///
+    /// They are cheaper to read but can lag by up to a tick, so adaptive
+    /// code may check them against a precise clock now and then.
+    #[inline]
+    pub const fn is_cached(&self) -> bool {
+        matches!(self, Self::MonotonicRawApprox | Self::UptimeRawApprox)
+    }
+
+    /// Returns the name of the C constant of this clock, e.g.
+    /// `"CLOCK_MONOTONIC"`, as printed by [`Display`](core::fmt::Display).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::MonotonicRaw => "CLOCK_MONOTONIC_RAW",
+            Self::MonotonicRawApprox => "CLOCK_MONOTONIC_RAW_APPROX",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::UptimeRaw => "CLOCK_UPTIME_RAW",
+            Self::UptimeRawApprox => "CLOCK_UPTIME_RAW_APPROX",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+}
+
 #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
 #[cfg_attr(target_os = "freebsd", repr(i32))]
 #[cfg_attr(target_os = "dragonfly", repr(u64))]
@@ -96,6 +165,89 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+impl ClockId {
+    // Every variant, to map raw ids back to the enum.
+    pub(crate) const VARIANTS: [Self; 14] = [
+        Self::Realtime,
+        Self::RealtimePrecise,
+        Self::RealtimeFast,
+        Self::Monotonic,
+        Self::MonotonicPrecise,
+        Self::MonotonicFast,
+        Self::Uptime,
+        Self::UptimePrecise,
+        Self::UptimeFast,
+        Self::Virtual,
+        Self::Prof,
+        Self::Second,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` for the clocks counting the wall-clock time since the
+    /// Unix epoch, see [`SystemTime::now_with_clock`](crate::SystemTime::now_with_clock).
+    #[inline]
+    pub const fn is_realtime(&self) -> bool {
+        matches!(
+            self,
+            Self::Realtime | Self::RealtimePrecise | Self::RealtimeFast | Self::Second
+        )
+    }
+
+    /// Returns `true` for the clocks that never go backwards and tick at the
+    /// pace of the wall-clock time, see
+    /// [`Instant::now_with_clock`](crate::Instant::now_with_clock).
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::Monotonic
+                | Self::MonotonicPrecise
+                | Self::MonotonicFast
+                | Self::Uptime
+                | Self::UptimePrecise
+                | Self::UptimeFast
+        )
+    }
+
+    /// Returns `true` for the clocks reading a value cached by the kernel,
+    /// e.g. at the last timer tick, instead of querying the time counter.
+    ///
+    /// They are cheaper to read but can lag by up to a tick, so adaptive
+    /// code may check them against a precise clock now and then.
+    #[inline]
+    pub const fn is_cached(&self) -> bool {
+        matches!(
+            self,
+            Self::RealtimeFast | Self::MonotonicFast | Self::UptimeFast | Self::Second
+        )
+    }
+
+    /// Returns the name of the C constant of this clock, e.g.
+    /// `"CLOCK_MONOTONIC"`, as printed by [`Display`](core::fmt::Display).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::RealtimePrecise => "CLOCK_REALTIME_PRECISE",
+            Self::RealtimeFast => "CLOCK_REALTIME_FAST",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::MonotonicPrecise => "CLOCK_MONOTONIC_PRECISE",
+            Self::MonotonicFast => "CLOCK_MONOTONIC_FAST",
+            Self::Uptime => "CLOCK_UPTIME",
+            Self::UptimePrecise => "CLOCK_UPTIME_PRECISE",
+            Self::UptimeFast => "CLOCK_UPTIME_FAST",
+            Self::Virtual => "CLOCK_VIRTUAL",
+            Self::Prof => "CLOCK_PROF",
+            Self::Second => "CLOCK_SECOND",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+}
+
 #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
 #[allow(non_upper_case_globals)]
 impl ClockId {
@@ -143,6 +295,59 @@ pub enum ClockId {
     ThreadCputimeId = self::sys::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(target_os = "netbsd")]
+impl ClockId {
+    // Every variant, to map raw ids back to the enum.
+    pub(crate) const VARIANTS: [Self; 6] = [
+        Self::Realtime,
+        Self::Monotonic,
+        Self::Virtual,
+        Self::Prof,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` for the clocks counting the wall-clock time since the
+    /// Unix epoch, see [`SystemTime::now_with_clock`](crate::SystemTime::now_with_clock).
+    #[inline]
+    pub const fn is_realtime(&self) -> bool {
+        matches!(self, Self::Realtime)
+    }
+
+    /// Returns `true` for the clocks that never go backwards and tick at the
+    /// pace of the wall-clock time, see
+    /// [`Instant::now_with_clock`](crate::Instant::now_with_clock).
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(self, Self::Monotonic)
+    }
+
+    /// Returns `true` for the clocks reading a value cached by the kernel,
+    /// e.g. at the last timer tick, instead of querying the time counter.
+    ///
+    /// They are cheaper to read but can lag by up to a tick, so adaptive
+    /// code may check them against a precise clock now and then.
+    #[inline]
+    pub const fn is_cached(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the C constant of this clock, e.g.
+    /// `"CLOCK_MONOTONIC"`, as printed by [`Display`](core::fmt::Display).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::Virtual => "CLOCK_VIRTUAL",
+            Self::Prof => "CLOCK_PROF",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+}
+
 #[cfg(target_os = "openbsd")]
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -166,11 +371,139 @@ pub enum ClockId {
     ThreadCputimeId = libc::CLOCK_THREAD_CPUTIME_ID,
 }
 
+#[cfg(target_os = "openbsd")]
+impl ClockId {
+    // Every variant, to map raw ids back to the enum.
+    pub(crate) const VARIANTS: [Self; 6] = [
+        Self::Realtime,
+        Self::Monotonic,
+        Self::Boottime,
+        Self::Uptime,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+    ];
+
+    /// Returns `true` for the clocks counting the wall-clock time since the
+    /// Unix epoch, see [`SystemTime::now_with_clock`](crate::SystemTime::now_with_clock).
+    #[inline]
+    pub const fn is_realtime(&self) -> bool {
+        matches!(self, Self::Realtime)
+    }
+
+    /// Returns `true` for the clocks that never go backwards and tick at the
+    /// pace of the wall-clock time, see
+    /// [`Instant::now_with_clock`](crate::Instant::now_with_clock).
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(self, Self::Monotonic | Self::Boottime | Self::Uptime)
+    }
+
+    /// Returns `true` for the clocks reading a value cached by the kernel,
+    /// e.g. at the last timer tick, instead of querying the time counter.
+    ///
+    /// They are cheaper to read but can lag by up to a tick, so adaptive
+    /// code may check them against a precise clock now and then.
+    #[inline]
+    pub const fn is_cached(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the C constant of this clock, e.g.
+    /// `"CLOCK_MONOTONIC"`, as printed by [`Display`](core::fmt::Display).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::Boottime => "CLOCK_BOOTTIME",
+            Self::Uptime => "CLOCK_UPTIME",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+        }
+    }
+}
+
+// The integer type `ClockId` is `repr`'d as, which must be exactly the
+// platform's `clockid_t`: it is `u32` on Apple platforms, `u64` on DragonFly
+// and `i32` elsewhere, so a blind `as _` could silently sign-convert or
+// truncate if the two ever drifted apart.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+type RawClockId = u32;
+#[cfg(target_os = "dragonfly")]
+type RawClockId = u64;
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+type RawClockId = i32;
+
+// Fails to compile unless `RawClockId` is `libc::clockid_t`...
+const _: fn(RawClockId) -> libc::clockid_t = core::convert::identity;
+// ...and unless it is the actual `repr` of `ClockId`.
+const _: () = assert!(core::mem::size_of::<ClockId>() == core::mem::size_of::<RawClockId>());
+
+impl ClockId {
+    /// Returns the id to pass to libc, a lossless conversion as checked above.
+    #[inline(always)]
+    pub(crate) const fn as_raw(self) -> libc::clockid_t {
+        self as RawClockId
+    }
+}
+
+/// A clock the `clock_*` functions can address, by its raw `clockid_t`.
+///
+/// Only the clocks of [`ClockId`] implement it here: dynamic clocks, addressed
+/// by file descriptor, are Linux-only. The readers of [`Timespec`] are generic
+/// over it, as on Linux.
+pub trait AsRawClockId {
+    /// Returns the raw clock id passed to libc.
+    fn as_raw_clock_id(&self) -> libc::clockid_t;
+}
+
+impl AsRawClockId for ClockId {
+    #[inline(always)]
+    fn as_raw_clock_id(&self) -> libc::clockid_t {
+        self.as_raw()
+    }
+}
+
+impl<C: AsRawClockId + ?Sized> AsRawClockId for &C {
+    #[inline(always)]
+    fn as_raw_clock_id(&self) -> libc::clockid_t {
+        (**self).as_raw_clock_id()
+    }
+}
+
+/// A time value as read from or written to a system clock.
+///
+/// Layout-compatible with the platform's libc `struct timespec`.
 #[repr(transparent)]
 #[derive(Clone, Copy)]
 pub struct Timespec(libc::timespec);
 
+/// Reads `clockid` into a caller-owned `out`.
+///
+/// This is the in-place counterpart of [`Timespec::now`], useful to fill
+/// preallocated slots without going through a temporary.
+#[inline]
+pub fn clock_gettime_into<C: AsRawClockId>(clockid: C, out: &mut Timespec) -> Result<(), Errno> {
+    if unsafe { libc::clock_gettime(clockid.as_raw_clock_id(), &mut out.0 as *mut _) } == -1 {
+        Err(Errno::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
 impl Timespec {
+    /// The earliest representable time.
+    pub const MIN: Self = Self::new(libc::time_t::MIN as i64, 0);
+
+    /// The latest representable time.
+    pub const MAX: Self = Self::new(libc::time_t::MAX as i64, 999_999_999);
+
     #[inline(always)]
     pub const fn new(secs: i64, nsecs: u32) -> Self {
         Self(libc::timespec {
@@ -180,15 +513,27 @@ impl Timespec {
     }
 
     #[inline(always)]
-    pub fn now(clockid: ClockId) -> Result<Self, Errno> {
+    pub fn now<C: AsRawClockId>(clockid: C) -> Result<Self, Errno> {
         let mut buf = MaybeUninit::<libc::timespec>::uninit();
-        if unsafe { libc::clock_gettime(clockid as _, buf.as_mut_ptr()) } == -1 {
+        if unsafe { libc::clock_gettime(clockid.as_raw_clock_id(), buf.as_mut_ptr()) } == -1 {
             Err(Errno::last_os_error())
         } else {
             Ok(Self(unsafe { buf.assume_init() }))
         }
     }
 
+    /// Returns the resolution of `clockid`, as reported by `clock_getres(2)`.
+    #[inline]
+    pub fn resolution<C: AsRawClockId>(clockid: C) -> Result<core::time::Duration, Errno> {
+        let mut buf = MaybeUninit::<libc::timespec>::uninit();
+        if unsafe { libc::clock_getres(clockid.as_raw_clock_id(), buf.as_mut_ptr()) } == -1 {
+            Err(Errno::last_os_error())
+        } else {
+            let res = Self(unsafe { buf.assume_init() });
+            Ok(core::time::Duration::new(res.secs() as u64, res.nsecs()))
+        }
+    }
+
     #[inline(always)]
     pub const fn secs(&self) -> i64 {
         self.0.tv_sec as _
@@ -209,9 +554,76 @@ impl Timespec {
         self.0.tv_nsec = nsecs as _;
     }
 
+    /// Returns `true` if `tv_nsec` fits in the 32 bits of the nanoseconds,
+    /// as it does in every timespec built or read by this crate.
+    ///
+    /// A `tv_nsec` beyond that range in a timespec received from FFI hints at
+    /// a layout mismatch on the other side.
+    #[inline]
+    pub const fn validate_padding(&self) -> bool {
+        self.0.tv_nsec as i64 == self.0.tv_nsec as u32 as i64
+    }
+
+    /// Sets the system clock, [`ClockId::Realtime`], to `self`.
     #[inline]
     pub fn set_clock(&self) -> Result<(), Errno> {
-        if unsafe { libc::clock_settime(ClockId::Realtime as _, &self.0 as *const _) } == -1 {
+        self.set_clock_id(ClockId::Realtime)
+    }
+
+    /// Sets `clockid` to `self` with `clock_settime(2)`.
+    ///
+    /// The error of the system is returned unchanged: `EINVAL` for a clock
+    /// that can't be set, e.g. [`ClockId::Monotonic`], `EPERM` without the
+    /// privileges to set it.
+    #[inline]
+    pub fn set_clock_id<C: AsRawClockId>(&self, clockid: C) -> Result<(), Errno> {
+        if unsafe { libc::clock_settime(clockid.as_raw_clock_id(), &self.0 as *const _) } == -1 {
+            Err(Errno::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Suspends the calling thread until `clockid` reaches the absolute time
+    /// `self`.
+    ///
+    /// Uses `clock_nanosleep(2)` with `TIMER_ABSTIME` where available, otherwise
+    /// reads `clockid` and `nanosleep(2)`s for the remaining time.
+    ///
+    /// Returns `Err(Errno::EINTR)` if the sleep is interrupted by a signal
+    /// handler, the call can simply be repeated with the same deadline.
+    #[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+    #[inline]
+    pub fn sleep_until<C: AsRawClockId>(&self, clockid: C) -> Result<(), Errno> {
+        match unsafe {
+            libc::clock_nanosleep(
+                clockid.as_raw_clock_id(),
+                libc::TIMER_ABSTIME,
+                &self.0 as *const _,
+                core::ptr::null_mut(),
+            )
+        } {
+            0 => Ok(()),
+            errno => Err(Errno::new(errno)),
+        }
+    }
+
+    /// Suspends the calling thread until `clockid` reaches the absolute time
+    /// `self`.
+    ///
+    /// Uses `clock_nanosleep(2)` with `TIMER_ABSTIME` where available, otherwise
+    /// reads `clockid` and `nanosleep(2)`s for the remaining time.
+    ///
+    /// Returns `Err(Errno::EINTR)` if the sleep is interrupted by a signal
+    /// handler, the call can simply be repeated with the same deadline.
+    #[cfg(not(any(target_os = "freebsd", target_os = "dragonfly")))]
+    pub fn sleep_until<C: AsRawClockId>(&self, clockid: C) -> Result<(), Errno> {
+        let remaining = match self.sub_timespec(&Self::now(clockid)?) {
+            Ok(remaining) => remaining,
+            Err(_) => return Ok(()),
+        };
+        let req = Self::from_duration(remaining);
+        if unsafe { libc::nanosleep(&req.0 as *const _, core::ptr::null_mut()) } == -1 {
             Err(Errno::last_os_error())
         } else {
             Ok(())
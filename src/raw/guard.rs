@@ -0,0 +1,141 @@
+//! Process-wide filter keeping [`Instant::now`](crate::Instant::now) readings
+//! monotonic even on platforms with buggy clocks.
+
+use core::{
+    ptr,
+    sync::atomic::{AtomicPtr, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use super::Timespec;
+
+// Every field of the guard is an atomic of its own, updated without locking.
+#[cfg(not(target_has_atomic = "64"))]
+compile_error!("the `monotonic-guard` feature needs 64-bit atomics");
+
+/// Backward jumps up to this size are considered clock jitter by default.
+pub const DEFAULT_BACKWARD_JUMP_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// Counters collected by the monotonic guard, see [`stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Number of readings that went backwards and have been clamped.
+    pub backward_jumps: u64,
+    /// Number of backward jumps larger than the configured threshold, see
+    /// [`set_backward_jump_threshold`].
+    pub large_backward_jumps: u64,
+}
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+// The latest reading, as nanoseconds: packed in a `u64`, it can be advanced
+// with a single `fetch_max`.
+static LAST: AtomicU64 = AtomicU64::new(0);
+static THRESHOLD: AtomicU64 =
+    AtomicU64::new(DEFAULT_BACKWARD_JUMP_THRESHOLD.as_secs() * NANOS_PER_SEC);
+// A `fn(Duration)`, null for none.
+static CALLBACK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+static BACKWARD_JUMPS: AtomicU64 = AtomicU64::new(0);
+static LARGE_BACKWARD_JUMPS: AtomicU64 = AtomicU64::new(0);
+
+#[inline]
+fn pack(t: Timespec) -> Option<u64> {
+    let secs = u64::try_from(t.secs()).ok()?;
+    secs.checked_mul(NANOS_PER_SEC)?
+        .checked_add(u64::from(t.nsecs()))
+}
+
+#[inline]
+fn unpack(nanos: u64) -> Timespec {
+    Timespec::new(
+        (nanos / NANOS_PER_SEC) as i64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+#[inline]
+fn saturating_nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Filters a monotonic clock reading, returning the latest reading seen so far
+/// if `reading` is earlier than it.
+///
+/// This is the filter applied by [`Instant::now`](crate::Instant::now): every
+/// backward jump is clamped, the ones larger than the threshold set with
+/// [`set_backward_jump_threshold`] are also counted in
+/// [`Stats::large_backward_jumps`] and reported to the callback set with
+/// [`set_backward_jump_callback`]. Feeding readings from other clocks affects
+/// subsequent `Instant`s.
+///
+/// Readings are tracked as nanoseconds in a `u64`: the rare ones this can't
+/// represent, before the clock's epoch or more than 584 years after it, are
+/// returned unfiltered.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::{self, Timespec};
+///
+/// raw::set_backward_jump_threshold(Duration::from_secs(1));
+/// raw::guard_monotonic(Timespec::new(100, 0));
+///
+/// // jitter is clamped silently
+/// let t = raw::guard_monotonic(Timespec::new(99, 999_999_000));
+/// assert_eq!(t, Timespec::new(100, 0));
+/// assert_eq!(raw::stats().large_backward_jumps, 0);
+///
+/// // a large jump is clamped and reported
+/// let t = raw::guard_monotonic(Timespec::new(90, 0));
+/// assert_eq!(t, Timespec::new(100, 0));
+/// assert_eq!(raw::stats().backward_jumps, 2);
+/// assert_eq!(raw::stats().large_backward_jumps, 1);
+/// ```
+pub fn guard_monotonic(reading: Timespec) -> Timespec {
+    let nanos = match pack(reading) {
+        Some(nanos) => nanos,
+        None => return reading,
+    };
+    let last = LAST.fetch_max(nanos, Ordering::Relaxed);
+    if nanos >= last {
+        return reading;
+    }
+
+    BACKWARD_JUMPS.fetch_add(1, Ordering::Relaxed);
+    let jump = last - nanos;
+    if jump > THRESHOLD.load(Ordering::Relaxed) {
+        LARGE_BACKWARD_JUMPS.fetch_add(1, Ordering::Relaxed);
+        let cb = CALLBACK.load(Ordering::Acquire);
+        if !cb.is_null() {
+            // SAFETY: only `fn(Duration)`s are stored in `CALLBACK`.
+            let cb = unsafe { core::mem::transmute::<*mut (), fn(Duration)>(cb) };
+            cb(Duration::from_nanos(jump));
+        }
+    }
+    unpack(last)
+}
+
+/// Sets the size above which a backward jump is reported instead of being
+/// treated as clock jitter. Defaults to [`DEFAULT_BACKWARD_JUMP_THRESHOLD`].
+#[inline]
+pub fn set_backward_jump_threshold(threshold: Duration) {
+    THRESHOLD.store(saturating_nanos(threshold), Ordering::Relaxed)
+}
+
+/// Sets the function called with the size of every backward jump larger than
+/// the threshold, see [`set_backward_jump_threshold`].
+#[inline]
+pub fn set_backward_jump_callback(callback: Option<fn(Duration)>) {
+    let cb = callback.map_or(ptr::null_mut(), |cb| cb as *mut ());
+    CALLBACK.store(cb, Ordering::Release)
+}
+
+/// Returns the counters collected by the monotonic guard.
+#[inline]
+pub fn stats() -> Stats {
+    Stats {
+        backward_jumps: BACKWARD_JUMPS.load(Ordering::Relaxed),
+        large_backward_jumps: LARGE_BACKWARD_JUMPS.load(Ordering::Relaxed),
+    }
+}
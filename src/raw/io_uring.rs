@@ -0,0 +1,80 @@
+//! Helpers to drive kernel clocks through an existing `io_uring`.
+//!
+//! `io_uring` has no operation returning the value of a clock: timestamps for
+//! completions are still taken with [`Timespec::now`], which goes through the
+//! vdso and doesn't enter the kernel anyway. What a ring can do is wait on a
+//! clock, batched with the rest of the I/O, with an `IORING_OP_TIMEOUT` entry
+//! expiring at an absolute time: [`prepare_abs_timeout`] prepares one.
+//!
+//! Only [`ClockId::Monotonic`] (the kernel default), [`ClockId::Boottime`]
+//! and [`ClockId::Realtime`] are accepted by the kernel for timeouts.
+
+use linux_raw_sys::io_uring::{
+    io_uring_op, IORING_TIMEOUT_ABS, IORING_TIMEOUT_BOOTTIME, IORING_TIMEOUT_REALTIME,
+};
+
+pub use linux_raw_sys::io_uring::io_uring_sqe;
+
+use super::{ClockId, Timespec};
+use crate::Errno;
+
+#[inline]
+fn clock_flags(clockid: ClockId) -> Result<u32, Errno> {
+    match clockid {
+        ClockId::Monotonic => Ok(0),
+        ClockId::Boottime => Ok(IORING_TIMEOUT_BOOTTIME),
+        ClockId::Realtime => Ok(IORING_TIMEOUT_REALTIME),
+        _ => Err(Errno::EINVAL),
+    }
+}
+
+/// Fills `sqe` with an `IORING_OP_TIMEOUT` that completes when `clockid`
+/// reaches the absolute time `deadline`.
+///
+/// The completion for the entry carries `-ETIME` in `res` when the deadline
+/// is reached. Fields not related to the timeout are zeroed, `user_data` and
+/// `flags` can be set after this call.
+///
+/// Returns `Err(Errno::EINVAL)` if `clockid` can't back an `io_uring` timeout.
+///
+/// # Safety
+///
+/// The entry only holds the address of `deadline`, which the kernel reads
+/// when the entry is submitted: `deadline` must stay alive and unmoved until
+/// `io_uring_enter(2)` has consumed the entry.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use unix_clock::raw::{
+///     io_uring::{io_uring_sqe, prepare_abs_timeout},
+///     ClockId, Timespec,
+/// };
+///
+/// let deadline = Timespec::now(ClockId::Monotonic)
+///     .unwrap()
+///     .checked_add_duration(&Duration::from_millis(10))
+///     .unwrap();
+/// let mut sqe: io_uring_sqe = unsafe { core::mem::zeroed() };
+/// // SAFETY: `deadline` outlives the submission below.
+/// unsafe { prepare_abs_timeout(&mut sqe, &deadline, ClockId::Monotonic) }.unwrap();
+/// // push `sqe` into the submission queue of the ring and submit it...
+/// ```
+pub unsafe fn prepare_abs_timeout(
+    sqe: &mut io_uring_sqe,
+    deadline: &Timespec,
+    clockid: ClockId,
+) -> Result<(), Errno> {
+    let flags = clock_flags(clockid)?;
+
+    // SAFETY: `io_uring_sqe` is a plain C struct, all zero is a valid (no-op)
+    // entry.
+    *sqe = unsafe { core::mem::zeroed() };
+    sqe.opcode = io_uring_op::IORING_OP_TIMEOUT as u8;
+    sqe.fd = -1;
+    sqe.__bindgen_anon_2.addr = deadline as *const Timespec as u64;
+    sqe.len = 1;
+    sqe.__bindgen_anon_3.timeout_flags = flags | IORING_TIMEOUT_ABS;
+    Ok(())
+}
@@ -1,17 +1,38 @@
+use core::mem::MaybeUninit;
+
 use cfg_if::cfg_if;
+#[cfg(not(feature = "std"))]
+use linux_raw_sys::ctypes::c_int as RawFd;
 use linux_syscalls::{syscall, Errno, Sysno};
+#[cfg(feature = "std")]
+use std::os::unix::io::RawFd;
 
+// The x32 ABI (x86_64 with 32-bit pointers) always had a 64-bit `time_t`:
+// like x86_64 it takes a `__kernel_timespec` in the plain syscalls and has
+// no `*_time64` variants, hence the `target_arch` check.
 cfg_if! {
     if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
         #[allow(non_upper_case_globals)]
         const SYS_clock_gettime: Sysno = Sysno::clock_gettime;
         #[allow(non_upper_case_globals)]
         const SYS_clock_settime: Sysno = Sysno::clock_settime;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_getres: Sysno = Sysno::clock_getres;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_adjtime: Sysno = Sysno::clock_adjtime;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_nanosleep: Sysno = Sysno::clock_nanosleep;
     } else {
         #[allow(non_upper_case_globals)]
         const SYS_clock_gettime: Sysno = Sysno::clock_gettime64;
         #[allow(non_upper_case_globals)]
         const SYS_clock_settime: Sysno = Sysno::clock_settime64;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_getres: Sysno = Sysno::clock_getres_time64;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_adjtime: Sysno = Sysno::clock_adjtime64;
+        #[allow(non_upper_case_globals)]
+        const SYS_clock_nanosleep: Sysno = Sysno::clock_nanosleep_time64;
     }
 }
 
@@ -102,6 +123,125 @@ pub enum ClockId {
     InternationalAtomicTime = linux_raw_sys::general::CLOCK_TAI,
 }
 
+impl ClockId {
+    // Every variant, to map raw ids back to the enum.
+    pub(crate) const VARIANTS: [Self; 11] = [
+        Self::Realtime,
+        Self::Monotonic,
+        Self::ProcessCputimeId,
+        Self::ThreadCputimeId,
+        Self::MonotonicRaw,
+        Self::RealtimeCoarse,
+        Self::MonotonicCoarse,
+        Self::Boottime,
+        Self::RealtimeAlarm,
+        Self::BoottimeAlarm,
+        Self::InternationalAtomicTime,
+    ];
+
+    /// Returns `true` for the clocks counting the wall-clock time since the
+    /// Unix epoch, see [`SystemTime::now_with_clock`](crate::SystemTime::now_with_clock).
+    #[inline]
+    pub const fn is_realtime(&self) -> bool {
+        matches!(
+            self,
+            Self::Realtime
+                | Self::RealtimeCoarse
+                | Self::RealtimeAlarm
+                | Self::InternationalAtomicTime
+        )
+    }
+
+    /// Returns `true` for the clocks that never go backwards and tick at the
+    /// pace of the wall-clock time, see
+    /// [`Instant::now_with_clock`](crate::Instant::now_with_clock).
+    #[inline]
+    pub const fn is_monotonic(&self) -> bool {
+        matches!(
+            self,
+            Self::Monotonic
+                | Self::MonotonicCoarse
+                | Self::MonotonicRaw
+                | Self::Boottime
+                | Self::BoottimeAlarm
+        )
+    }
+
+    /// Returns `true` for the clocks reading a value cached by the kernel,
+    /// e.g. at the last timer tick, instead of querying the time counter.
+    ///
+    /// They are cheaper to read but can lag by up to a tick, so adaptive
+    /// code may check them against a precise clock now and then.
+    #[inline]
+    pub const fn is_cached(&self) -> bool {
+        matches!(self, Self::RealtimeCoarse | Self::MonotonicCoarse)
+    }
+
+    /// Returns the name of the C constant of this clock, e.g.
+    /// `"CLOCK_MONOTONIC"`, as printed by [`Display`](core::fmt::Display).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Realtime => "CLOCK_REALTIME",
+            Self::Monotonic => "CLOCK_MONOTONIC",
+            Self::ProcessCputimeId => "CLOCK_PROCESS_CPUTIME_ID",
+            Self::ThreadCputimeId => "CLOCK_THREAD_CPUTIME_ID",
+            Self::MonotonicRaw => "CLOCK_MONOTONIC_RAW",
+            Self::RealtimeCoarse => "CLOCK_REALTIME_COARSE",
+            Self::MonotonicCoarse => "CLOCK_MONOTONIC_COARSE",
+            Self::Boottime => "CLOCK_BOOTTIME",
+            Self::RealtimeAlarm => "CLOCK_REALTIME_ALARM",
+            Self::BoottimeAlarm => "CLOCK_BOOTTIME_ALARM",
+            Self::InternationalAtomicTime => "CLOCK_TAI",
+        }
+    }
+
+    /// Returns `true` if the running kernel implements this clock.
+    ///
+    /// Clocks newer than the kernel, e.g. [`ClockId::InternationalAtomicTime`]
+    /// before Linux 3.10, fail every read with `EINVAL`. The probe for
+    /// `InternationalAtomicTime` is a full read, done once and cached; the
+    /// other clocks are probed with `clock_getres(2)` on every call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::ClockId;
+    ///
+    /// assert!(ClockId::Realtime.is_supported());
+    /// assert!(ClockId::Monotonic.is_supported());
+    /// let _ = ClockId::InternationalAtomicTime.is_supported();
+    /// ```
+    pub fn is_supported(&self) -> bool {
+        use core::sync::atomic::{AtomicU8, Ordering};
+
+        const UNKNOWN: u8 = 0;
+        const SUPPORTED: u8 = 1;
+        const UNSUPPORTED: u8 = 2;
+        static TAI: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+        match self {
+            Self::InternationalAtomicTime => match TAI.load(Ordering::Relaxed) {
+                UNKNOWN => {
+                    let supported = Timespec::now(*self).is_ok();
+                    TAI.store(
+                        if supported { SUPPORTED } else { UNSUPPORTED },
+                        Ordering::Relaxed,
+                    );
+                    supported
+                }
+                state => state == SUPPORTED,
+            },
+            _ => Timespec::resolution(*self).is_ok(),
+        }
+    }
+}
+
+/// A time value as read from or written to a kernel clock.
+///
+/// Layout-compatible with the kernel's `struct __kernel_timespec`: a 64-bit
+/// `tv_sec` followed by a 64-bit `tv_nsec` whose unused half is kept zeroed.
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Timespec {
@@ -113,6 +253,22 @@ pub struct Timespec {
     __padding: i32,
 }
 
+// `__kernel_timespec` is two `long long`s on every architecture, x32 and the
+// 32-bit ones included: 16 bytes aligned like an `i64`.
+const _: () = assert!(core::mem::size_of::<Timespec>() == 16);
+const _: () = assert!(core::mem::align_of::<Timespec>() == core::mem::align_of::<i64>());
+
+// The kernel reads and writes `tv_nsec` as an `i64` on every architecture,
+// through the `*_time64` syscalls on the 32-bit ones. Its value is below one
+// second, so the high half is zero: `tv_nsec` is the low half and `__padding`
+// the high one, hence the padding first on big-endian targets. The pair must
+// read back as a native `i64`.
+const _: () = {
+    let t = Timespec::new(-1, 999_999_999);
+    let [sec, nsec] = unsafe { core::mem::transmute::<Timespec, [i64; 2]>(t) };
+    assert!(sec == -1 && nsec == 999_999_999);
+};
+
 cfg_if! {
     if #[cfg(any(
         target_arch = "x86_64", // supporting x32 too
@@ -123,17 +279,18 @@ cfg_if! {
         target_pointer_width = "64"
     ))] {
         mod get_impl {
-            use core::{
-                mem::MaybeUninit,
-                sync::atomic::{AtomicPtr, Ordering},
-            };
+            use core::sync::atomic::{AtomicPtr, Ordering};
 
             use linux_syscalls::{syscall, Errno};
 
+            type ClockGettime = extern "C" fn(i32, *mut super::Timespec) -> usize;
+            // `clock_getres` has the same signature.
+            type ClockGetres = ClockGettime;
+
             const UNINIT: *mut core::ffi::c_void = core::ptr::null_mut();
-            const INIT_NULL: *mut core::ffi::c_void = 1 as _;
-            static mut CLOCK_GETTIME_VSYSCALL: AtomicPtr<core::ffi::c_void> =
-                AtomicPtr::new(core::ptr::null_mut());
+            const DISABLED: *mut core::ffi::c_void = 1 as _;
+            static CLOCK_GETTIME_VSYSCALL: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(UNINIT);
+            static CLOCK_GETRES_VSYSCALL: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(UNINIT);
 
             cfg_if::cfg_if! {
                 if #[cfg(any(
@@ -146,66 +303,314 @@ cfg_if! {
                     fn vdso_clock_gettime(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
                         vdso.clock_gettime64()
                     }
+
+                    #[inline(always)]
+                    fn vdso_clock_getres(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        vdso.clock_getres_time64()
+                    }
                 } else {
                     #[inline(always)]
                     fn vdso_clock_gettime(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
                         vdso.clock_gettime()
                     }
+
+                    #[inline(always)]
+                    fn vdso_clock_getres(vdso: &linux_syscalls::env::Vdso) -> *const core::ffi::c_void {
+                        vdso.clock_getres()
+                    }
                 }
             }
 
             #[inline(always)]
-            fn clock_gettime_vsyscall(
-            ) -> Option<extern "C" fn(super::ClockId, *mut super::Timespec) -> usize> {
-                unsafe {
-                    match CLOCK_GETTIME_VSYSCALL.load(Ordering::Relaxed) {
-                        UNINIT => {
-                            let ptr =
-                                vdso_clock_gettime(linux_syscalls::env::unchecked_vdso()) as *mut core::ffi::c_void;
-                            if ptr.is_null() {
-                                CLOCK_GETTIME_VSYSCALL.store(INIT_NULL, Ordering::Relaxed);
-                                None
-                            } else {
-                                CLOCK_GETTIME_VSYSCALL.store(ptr, Ordering::Relaxed);
-                                Some(core::mem::transmute(ptr))
-                            }
+            fn vsyscall(
+                cache: &AtomicPtr<core::ffi::c_void>,
+                lookup: fn(&linux_syscalls::env::Vdso) -> *const core::ffi::c_void,
+            ) -> Option<*mut core::ffi::c_void> {
+                match cache.load(Ordering::Relaxed) {
+                    UNINIT => {
+                        // A null pointer is not cached: it is also what an
+                        // environment that has not been initialized yet
+                        // returns, and the vdso may show up later.
+                        let ptr = lookup(linux_syscalls::env::vdso()) as *mut core::ffi::c_void;
+                        if ptr.is_null() {
+                            return None;
+                        }
+                        // Only an uninitialized cache is filled: a concurrent
+                        // `disable_vdso` must not be undone.
+                        match cache.compare_exchange(UNINIT, ptr, Ordering::Relaxed, Ordering::Relaxed) {
+                            Ok(_) => Some(ptr),
+                            Err(DISABLED) => None,
+                            Err(ptr) => Some(ptr),
                         }
-                        INIT_NULL => None,
-                        ptr => Some(core::mem::transmute(ptr)),
                     }
+                    DISABLED => None,
+                    ptr => Some(ptr),
                 }
             }
 
-            pub fn clock_gettime(clockid: super::ClockId) -> Result<super::Timespec, Errno> {
-                unsafe {
-                    let mut buf = MaybeUninit::<super::Timespec>::uninit();
-                    (*buf.as_mut_ptr()).__padding = 0;
-                    if let Some(inner) = clock_gettime_vsyscall() {
-                        match Errno::from_ret(inner(clockid, buf.as_mut_ptr())) {
-                            Err(Errno::ENOSYS) => (),
-                            other => return other.map(|_| buf.assume_init()),
-                        }
+            #[inline(always)]
+            fn clock_gettime_vsyscall() -> Option<ClockGettime> {
+                let ptr = vsyscall(&CLOCK_GETTIME_VSYSCALL, vdso_clock_gettime)?;
+                // SAFETY: the pointer is the vdso's `clock_gettime`.
+                Some(unsafe { core::mem::transmute::<*mut core::ffi::c_void, ClockGettime>(ptr) })
+            }
+
+            #[inline(always)]
+            fn clock_getres_vsyscall() -> Option<ClockGetres> {
+                let ptr = vsyscall(&CLOCK_GETRES_VSYSCALL, vdso_clock_getres)?;
+                // SAFETY: the pointer is the vdso's `clock_getres`.
+                Some(unsafe { core::mem::transmute::<*mut core::ffi::c_void, ClockGetres>(ptr) })
+            }
+
+            #[inline]
+            pub fn using_vdso() -> bool {
+                clock_gettime_vsyscall().is_some()
+            }
+
+            #[inline]
+            pub fn disable_vdso() {
+                CLOCK_GETTIME_VSYSCALL.store(DISABLED, Ordering::Relaxed);
+                CLOCK_GETRES_VSYSCALL.store(DISABLED, Ordering::Relaxed);
+            }
+
+            /// # Safety
+            ///
+            /// `buf` must be valid for writes.
+            #[inline(always)]
+            pub unsafe fn clock_gettime(clockid: i32, buf: *mut super::Timespec) -> Result<(), Errno> {
+                (*buf).__padding = 0;
+                if let Some(inner) = clock_gettime_vsyscall() {
+                    match Errno::from_ret(inner(clockid, buf)) {
+                        Err(Errno::ENOSYS) => (),
+                        other => return other.map(|_| ()),
                     }
+                }
 
-                    syscall!(super::SYS_clock_gettime, clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
+                syscall!(super::SYS_clock_gettime, clockid, buf).map(|_| ())
+            }
+
+            /// # Safety
+            ///
+            /// `buf` must be valid for writes.
+            #[inline]
+            pub unsafe fn clock_getres(clockid: i32, buf: *mut super::Timespec) -> Result<(), Errno> {
+                (*buf).__padding = 0;
+                if let Some(inner) = clock_getres_vsyscall() {
+                    match Errno::from_ret(inner(clockid, buf)) {
+                        Err(Errno::ENOSYS) => (),
+                        other => return other.map(|_| ()),
+                    }
                 }
+
+                syscall!(super::SYS_clock_getres, clockid, buf).map(|_| ())
             }
         }
     } else {
         mod get_impl {
+            use linux_syscalls::{syscall, Errno};
+
+            #[inline]
+            pub fn using_vdso() -> bool {
+                false
+            }
+
+            #[inline]
+            pub fn disable_vdso() {}
+
+            /// # Safety
+            ///
+            /// `buf` must be valid for writes.
             #[inline(always)]
-            pub fn clock_gettime(clockid: super::ClockId) -> Result<super::Timespec, Errno> {
-                unsafe {
-                    let mut buf = MaybeUninit::<super::Timespec>::uninit();
-                    (*buf.as_mut_ptr()).__padding = 0;
-                    syscall!(super::SYS_clock_gettime, clockid, buf.as_mut_ptr()).map(|_| buf.assume_init())
-                }
+            pub unsafe fn clock_gettime(clockid: i32, buf: *mut super::Timespec) -> Result<(), Errno> {
+                (*buf).__padding = 0;
+                syscall!(super::SYS_clock_gettime, clockid, buf).map(|_| ())
+            }
+
+            /// # Safety
+            ///
+            /// `buf` must be valid for writes.
+            #[inline]
+            pub unsafe fn clock_getres(clockid: i32, buf: *mut super::Timespec) -> Result<(), Errno> {
+                (*buf).__padding = 0;
+                syscall!(super::SYS_clock_getres, clockid, buf).map(|_| ())
             }
         }
     }
 }
 
+/// Reads `clockid` into a caller-owned `out`, the padding of which gets zeroed.
+///
+/// This is the in-place counterpart of [`Timespec::now`], useful to fill
+/// preallocated slots without going through a temporary.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{clock_gettime_into, ClockId, Timespec};
+///
+/// let mut out = Timespec::zero();
+/// let mut prev = out;
+/// for _ in 0..100 {
+///     clock_gettime_into(ClockId::Monotonic, &mut out).unwrap();
+///     assert!(out >= prev);
+///     prev = out;
+/// }
+/// ```
+#[inline]
+pub fn clock_gettime_into<C: AsRawClockId>(clockid: C, out: &mut Timespec) -> Result<(), Errno> {
+    unsafe { get_impl::clock_gettime(clockid.as_raw_clock_id(), out) }
+}
+
+/// The time of a [`Timex`], in microseconds or, with `STA_NANO` set in
+/// [`Timex::status`], nanoseconds.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TimexTime {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+/// The kernel's `struct __kernel_timex`, the state of the clock discipline
+/// read or written by [`clock_adjtime`]. See `adjtimex(2)` for the meaning of
+/// each field.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Timex {
+    pub modes: u32,
+    __padding1: i32,
+    pub offset: i64,
+    pub freq: i64,
+    pub maxerror: i64,
+    pub esterror: i64,
+    pub status: i32,
+    __padding2: i32,
+    pub constant: i64,
+    pub precision: i64,
+    pub tolerance: i64,
+    pub time: TimexTime,
+    pub tick: i64,
+    pub ppsfreq: i64,
+    pub jitter: i64,
+    pub shift: i32,
+    __padding3: i32,
+    pub stabil: i64,
+    pub jitcnt: i64,
+    pub calcnt: i64,
+    pub errcnt: i64,
+    pub stbcnt: i64,
+    pub tai: i32,
+    __padding4: [i32; 11],
+}
+
+/// Reads and, depending on `buf.modes`, adjusts the discipline of `clockid`
+/// with `clock_adjtime(2)`.
+///
+/// Returns the clock state, one of the `TIME_*` constants of `adjtimex(2)`.
+/// With `buf.modes` set to zero no privilege is required.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{clock_adjtime, ClockId, Timex};
+///
+/// let mut timex = Timex::default();
+/// let state = clock_adjtime(ClockId::Realtime, &mut timex).unwrap();
+/// assert!((0..=5).contains(&state));
+/// ```
+#[inline]
+pub fn clock_adjtime<C: AsRawClockId>(clockid: C, buf: &mut Timex) -> Result<i32, Errno> {
+    unsafe {
+        syscall!(
+            SYS_clock_adjtime,
+            clockid.as_raw_clock_id(),
+            buf as *mut Timex
+        )
+    }
+    .map(|state| state as i32)
+}
+
+const PR_SET_TIMERSLACK: usize = 29;
+const PR_GET_TIMERSLACK: usize = 30;
+
+/// Sets the timer slack of the calling thread to `ns` nanoseconds, see
+/// `prctl(2)`.
+///
+/// The kernel may delay the wake-up of every sleep and timed wait of the
+/// thread by up to its slack, 50µs by default, to coalesce wake-ups. A
+/// smaller slack makes sleeps like [`precise_sleep`](crate::precise_sleep)
+/// wake up closer to their deadline, at the cost of more wake-ups and so
+/// more power usage. A slack of zero restores the thread's default.
+///
+/// Fails with `EINVAL` if `ns` doesn't fit in an `unsigned long`.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{get_timer_slack, set_timer_slack};
+///
+/// let old = get_timer_slack().unwrap();
+/// set_timer_slack(1_000).unwrap();
+/// assert_eq!(get_timer_slack(), Ok(1_000));
+/// // restore the slack the thread had
+/// set_timer_slack(old).unwrap();
+/// assert_eq!(get_timer_slack(), Ok(old));
+/// ```
+#[inline]
+pub fn set_timer_slack(ns: u64) -> Result<(), Errno> {
+    let ns = usize::try_from(ns).map_err(|_| Errno::EINVAL)?;
+    unsafe { syscall!(Sysno::prctl, PR_SET_TIMERSLACK, ns) }.map(|_| ())
+}
+
+/// Returns the timer slack of the calling thread in nanoseconds, see
+/// [`set_timer_slack`].
+#[inline]
+pub fn get_timer_slack() -> Result<u64, Errno> {
+    unsafe { syscall!([ro] Sysno::prctl, PR_GET_TIMERSLACK) }.map(|ns| ns as u64)
+}
+
+/// Returns `true` if `clock_gettime` is resolved through the vdso, `false` if
+/// every clock read is a real syscall.
+///
+/// The vdso is only used on architectures where the kernel exports
+/// `clock_gettime` to userspace, and only if the running kernel does.
+///
+/// Its address is taken from the environment `linux_syscalls` collects at
+/// startup. Binaries with an unusual entry point (e.g. `#[no_main]`) may read
+/// the clock before that environment is initialized: reads then fall back to
+/// the syscall, and switch to the vdso once it becomes available.
+#[inline]
+pub fn using_vdso() -> bool {
+    get_impl::using_vdso()
+}
+
+/// Stops resolving `clock_gettime` and `clock_getres` through the vdso: every
+/// subsequent clock read is a real syscall.
+///
+/// This is the path taken when no vdso is available, forcing it allows
+/// testing it or working around a misbehaving vdso.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::{raw, Instant};
+///
+/// let before = Instant::now();
+/// raw::disable_vdso();
+/// assert!(!raw::using_vdso());
+/// assert!(Instant::now() >= before);
+/// ```
+#[inline]
+pub fn disable_vdso() {
+    get_impl::disable_vdso()
+}
+
 impl Timespec {
+    /// The earliest representable time.
+    pub const MIN: Self = Self::new(i64::MIN, 0);
+
+    /// The latest representable time.
+    pub const MAX: Self = Self::new(i64::MAX, 999_999_999);
+
     #[inline(always)]
     pub const fn new(secs: i64, nsecs: u32) -> Self {
         Self {
@@ -216,8 +621,38 @@ impl Timespec {
     }
 
     #[inline(always)]
-    pub fn now(clockid: ClockId) -> Result<Self, Errno> {
-        get_impl::clock_gettime(clockid)
+    pub fn now<C: AsRawClockId>(clockid: C) -> Result<Self, Errno> {
+        let mut buf = MaybeUninit::<Self>::uninit();
+        unsafe {
+            get_impl::clock_gettime(clockid.as_raw_clock_id(), buf.as_mut_ptr())
+                .map(|_| buf.assume_init())
+        }
+    }
+
+    /// Returns the resolution of `clockid`, as reported by `clock_getres(2)`.
+    ///
+    /// Like [`Timespec::now`] it goes through the vdso when it exports
+    /// `clock_getres`, falling back to the syscall otherwise, and on
+    /// `ENOSYS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::{ClockId, Timespec};
+    ///
+    /// let res = Timespec::resolution(ClockId::Monotonic).unwrap();
+    /// assert!(res > Duration::ZERO && res <= Duration::from_millis(10));
+    /// assert!(Timespec::resolution(ClockId::MonotonicCoarse).unwrap() >= res);
+    /// ```
+    #[inline]
+    pub fn resolution<C: AsRawClockId>(clockid: C) -> Result<core::time::Duration, Errno> {
+        let mut buf = MaybeUninit::<Self>::uninit();
+        unsafe {
+            get_impl::clock_getres(clockid.as_raw_clock_id(), buf.as_mut_ptr())
+                .map(|_| buf.assume_init())
+        }
+        .map(|buf| core::time::Duration::new(buf.secs() as u64, buf.nsecs()))
     }
 
     #[inline(always)]
@@ -240,9 +675,136 @@ impl Timespec {
         self.tv_nsec = nsecs;
     }
 
+    /// Returns `true` if the unused half of `tv_nsec` is zeroed, as it is in
+    /// every timespec built or read by this crate.
+    ///
+    /// A nonzero padding in a timespec received from FFI hints at a layout
+    /// mismatch on the other side, e.g. a 32-bit `time_t`.
+    #[inline]
+    pub const fn validate_padding(&self) -> bool {
+        self.__padding == 0
+    }
+
+    /// Sets the system clock, [`ClockId::Realtime`], to `self`.
     #[inline]
     pub fn set_clock(&self) -> Result<(), Errno> {
-        unsafe { syscall!([ro] SYS_clock_settime, ClockId::Realtime, self as *const Self) }
+        self.set_clock_id(ClockId::Realtime)
+    }
+
+    /// Sets `clockid` to `self` with `clock_settime(2)`.
+    ///
+    /// The error of the kernel is returned unchanged: `EINVAL` for a clock
+    /// that can't be set, e.g. [`ClockId::Monotonic`], `EPERM` without the
+    /// privileges to set it.
+    #[inline]
+    pub fn set_clock_id<C: AsRawClockId>(&self, clockid: C) -> Result<(), Errno> {
+        unsafe { syscall!([ro] SYS_clock_settime, clockid.as_raw_clock_id(), self as *const Self) }
             .map(|_| ())
     }
+
+    /// Suspends the calling thread until `clockid` reaches the absolute time
+    /// `self`, with `clock_nanosleep(2)` and `TIMER_ABSTIME`.
+    ///
+    /// Returns `Err(Errno::EINTR)` if the sleep is interrupted by a signal
+    /// handler, the call can simply be repeated with the same deadline.
+    #[inline]
+    pub fn sleep_until<C: AsRawClockId>(&self, clockid: C) -> Result<(), Errno> {
+        unsafe {
+            syscall!(
+                SYS_clock_nanosleep,
+                clockid.as_raw_clock_id(),
+                linux_raw_sys::general::TIMER_ABSTIME,
+                self as *const Self,
+                core::ptr::null_mut::<Self>()
+            )
+        }
+        .map(|_| ())
+    }
+}
+
+/// A clock the `clock_*` syscalls can address, by its raw `clockid_t`.
+///
+/// Implemented by the static clocks of [`ClockId`] and by [`DynamicClock`]:
+/// [`Timespec::now`], [`Timespec::resolution`], [`Timespec::set_clock_id`],
+/// [`Timespec::sleep_until`], [`PosixTimer::new`](super::PosixTimer::new),
+/// [`clock_gettime_into`] and [`clock_adjtime`] take either. Elsewhere only
+/// [`ClockId`] implements it, the other platforms having no dynamic clocks.
+pub trait AsRawClockId {
+    /// Returns the raw clock id passed to the kernel.
+    fn as_raw_clock_id(&self) -> i32;
+}
+
+impl AsRawClockId for ClockId {
+    #[inline(always)]
+    fn as_raw_clock_id(&self) -> i32 {
+        i32::from(*self)
+    }
+}
+
+impl<C: AsRawClockId + ?Sized> AsRawClockId for &C {
+    #[inline(always)]
+    fn as_raw_clock_id(&self) -> i32 {
+        (**self).as_raw_clock_id()
+    }
+}
+
+// `CLOCKFD` of the kernel's `posix-timers.h`, tagging the dynamic clock ids.
+const CLOCKFD: i32 = 3;
+
+/// A dynamic POSIX clock, e.g. a PTP hardware clock exposed as `/dev/ptp0`,
+/// addressed through a file descriptor open on its character device.
+///
+/// Dynamic clock ids don't fit in [`ClockId`], which only holds the static
+/// clocks, but both are [`AsRawClockId`]: the readers of [`Timespec`] take
+/// either. The kernel resolves the id back to the file descriptor at every
+/// call: the caller must keep it open as long as the clock is used.
+/// Linux-only.
+///
+/// Reading the clock fails with `EINVAL` if the file descriptor isn't open on
+/// a clock device, and setting it requires the descriptor to be open for
+/// writing.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{fs::OpenOptions, os::unix::io::AsRawFd};
+/// use unix_clock::raw::{DynamicClock, Timespec};
+///
+/// let ptp = OpenOptions::new().read(true).write(true).open("/dev/ptp0").unwrap();
+/// let clock = DynamicClock::from_fd(ptp.as_raw_fd());
+/// println!("PHC time: {:?}", Timespec::now(clock).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynamicClock(i32);
+
+impl DynamicClock {
+    /// Returns the clock of the device open as `fd`, encoded like the
+    /// `FD_TO_CLOCKID` macro: `((~fd) << 3) | 3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::DynamicClock;
+    ///
+    /// assert_eq!(DynamicClock::from_fd(3).as_raw(), -29);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_fd(fd: RawFd) -> Self {
+        Self(((!fd) << 3) | CLOCKFD)
+    }
+
+    /// Returns the raw clock id, a negative `clockid_t`.
+    #[must_use]
+    #[inline]
+    pub const fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl AsRawClockId for DynamicClock {
+    #[inline(always)]
+    fn as_raw_clock_id(&self) -> i32 {
+        self.0
+    }
 }
@@ -6,6 +6,45 @@ mod inner;
 
 pub use inner::*;
 
+#[cfg(feature = "rkyv")]
+mod archive;
+mod clock_id;
+#[cfg(feature = "monotonic-guard")]
+mod guard;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+pub mod io_uring;
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+mod self_test;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos",
+    target_os = "openbsd"
+)))]
+mod timer;
+mod transaction;
+
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedTimespec;
+pub use clock_id::ClockIdError;
+#[cfg(feature = "monotonic-guard")]
+pub use guard::*;
+#[cfg(all(target_os = "linux", feature = "std"))]
+pub use self_test::*;
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos",
+    target_os = "openbsd"
+)))]
+pub use timer::*;
+pub use transaction::ClockTransaction;
+
 #[cfg(any(
     target_os = "macos",
     target_os = "ios",
@@ -13,25 +52,277 @@ pub use inner::*;
     target_os = "tvos"
 ))]
 pub(crate) const INSTANT_CLOCKID: ClockId = ClockId::UptimeRaw;
+#[cfg(all(target_os = "linux", feature = "boottime-instant"))]
+pub(crate) const INSTANT_CLOCKID: ClockId = ClockId::Boottime;
 #[cfg(not(any(
     target_os = "macos",
     target_os = "ios",
     target_os = "watchos",
-    target_os = "tvos"
+    target_os = "tvos",
+    all(target_os = "linux", feature = "boottime-instant")
 )))]
 pub(crate) const INSTANT_CLOCKID: ClockId = ClockId::Monotonic;
 
+// The clock of `Instant::now_raw`, ticking at the hardware pace: unaffected by
+// NTP and `adjtime(3)` slewing. Apple's `Instant` clock already is.
+#[cfg(target_os = "linux")]
+pub(crate) const RAW_INSTANT_CLOCKID: ClockId = ClockId::MonotonicRaw;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+pub(crate) const RAW_INSTANT_CLOCKID: ClockId = ClockId::UptimeRaw;
+
 pub(crate) const SYSTEM_TIME_CLOCKID: ClockId = ClockId::Realtime;
 
+// The clock of `BootInstant`: one counting suspended time where there is one.
+// Apple's `CLOCK_MONOTONIC` is `mach_continuous_time`, which does. FreeBSD's
+// and DragonFly's `CLOCK_UPTIME` doesn't, it is only the closest match.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "openbsd",
+    target_os = "freebsd",
+    target_os = "dragonfly"
+))]
+pub(crate) const BOOT_INSTANT_CLOCKID: ClockId = ClockId::Boottime;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "openbsd",
+    target_os = "freebsd",
+    target_os = "dragonfly"
+)))]
+pub(crate) const BOOT_INSTANT_CLOCKID: ClockId = ClockId::Monotonic;
+
+/// The clock read by [`Instant::now`](crate::Instant::now) on this platform
+/// and feature set.
+///
+/// `UptimeRaw` on Apple platforms, `Boottime` on Linux with the
+/// `boottime-instant` feature and `Monotonic` everywhere else.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::ACTIVE_INSTANT_CLOCK;
+///
+/// assert!(ACTIVE_INSTANT_CLOCK.is_monotonic());
+/// ```
+pub const ACTIVE_INSTANT_CLOCK: ClockId = INSTANT_CLOCKID;
+
+/// The clock read by [`SystemTime::now`](crate::SystemTime::now), `Realtime`
+/// everywhere.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::ACTIVE_SYSTEM_CLOCK;
+///
+/// assert!(ACTIVE_SYSTEM_CLOCK.is_realtime());
+/// ```
+pub const ACTIVE_SYSTEM_CLOCK: ClockId = SYSTEM_TIME_CLOCKID;
+
+#[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "dragonfly"))]
+pub(crate) const COARSE_REALTIME_CLOCKID: ClockId = ClockId::RealtimeCoarse;
+#[cfg(not(any(target_os = "linux", target_os = "freebsd", target_os = "dragonfly")))]
+pub(crate) const COARSE_REALTIME_CLOCKID: ClockId = ClockId::Realtime;
+
+// The cached counterpart of `INSTANT_CLOCKID`, on the same timeline. There is
+// no coarse boot time clock, so `boottime-instant` keeps the precise one.
+#[cfg(any(
+    all(target_os = "linux", not(feature = "boottime-instant")),
+    target_os = "freebsd",
+    target_os = "dragonfly"
+))]
+pub(crate) const COARSE_INSTANT_CLOCKID: ClockId = ClockId::MonotonicCoarse;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+pub(crate) const COARSE_INSTANT_CLOCKID: ClockId = ClockId::UptimeRawApprox;
+#[cfg(not(any(
+    all(target_os = "linux", not(feature = "boottime-instant")),
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+)))]
+pub(crate) const COARSE_INSTANT_CLOCKID: ClockId = INSTANT_CLOCKID;
+
+/// Size in bytes of the active [`Timespec`] layout.
+///
+/// On Linux it is always the kernel's 64-bit `__kernel_timespec` (16 bytes),
+/// the one accepted by the `*_time64` syscalls on 32-bit architectures too.
+/// On every other platform it is the libc `struct timespec`, whose size
+/// depends on the width of `time_t` and `long`.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{Timespec, TIMESPEC_SIZE};
+///
+/// assert_eq!(TIMESPEC_SIZE, core::mem::size_of::<Timespec>());
+/// ```
+pub const TIMESPEC_SIZE: usize = core::mem::size_of::<Timespec>();
+
+/// Alignment in bytes of the active [`Timespec`] layout.
+///
+/// See [`TIMESPEC_SIZE`] for the layout used on each platform.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::{Timespec, TIMESPEC_ALIGN};
+///
+/// assert_eq!(TIMESPEC_ALIGN, core::mem::align_of::<Timespec>());
+/// ```
+pub const TIMESPEC_ALIGN: usize = core::mem::align_of::<Timespec>();
+
+/// Size in bytes of the wire encoding of a [`Timespec`], see
+/// [`Timespec::to_wire`].
+pub const WIRE_SIZE: usize = 12;
+
 const NSEC_PER_SEC: u64 = 1_000_000_000;
 const I64_MAX: u64 = 9_223_372_036_854_775_807;
 
+/// Reads `clockid`, panicking with a message naming the clock and the error,
+/// e.g. `clock_gettime(CLOCK_MONOTONIC) failed: EPERM` when a sandbox blocks it.
+#[inline]
+pub(crate) fn now_or_panic(clockid: ClockId) -> Timespec {
+    match Timespec::now(clockid) {
+        Ok(t) => t,
+        Err(errno) => clock_gettime_failed(clockid, errno),
+    }
+}
+
+#[cold]
+#[inline(never)]
+pub(crate) fn clock_gettime_failed(clockid: ClockId, errno: crate::Errno) -> ! {
+    match errno.name() {
+        Some(name) => panic!("clock_gettime({}) failed: {}", clockid.name(), name),
+        None => panic!(
+            "clock_gettime({}) failed: errno {}",
+            clockid.name(),
+            errno.into_raw()
+        ),
+    }
+}
+
+/// Reads the clock backing [`Instant`](crate::Instant) as nanoseconds.
+///
+/// The count starts from an unspecified point, usually the boot, and wraps
+/// after ~584 years.
+#[inline]
+pub fn monotonic_now_ns() -> Result<u64, crate::Errno> {
+    let t = Timespec::now(INSTANT_CLOCKID)?;
+    Ok((t.secs() as u64)
+        .wrapping_mul(NSEC_PER_SEC)
+        .wrapping_add(t.nsecs() as u64))
+}
+
+/// Reads the clock backing [`Instant`](crate::Instant) once per slot of `out`,
+/// storing nanoseconds as [`monotonic_now_ns`] returns them.
+///
+/// Plain `u64`s pack densely in columnar telemetry buffers, without the
+/// padding of [`Timespec`]. The values are non-decreasing along the slice.
+/// On error the slots already read keep their value and the rest are left
+/// untouched.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::raw::fill_monotonic_nanos;
+///
+/// let mut column = [0u64; 64];
+/// fill_monotonic_nanos(&mut column).unwrap();
+/// assert!(column[0] > 0);
+/// assert!(column.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub fn fill_monotonic_nanos(out: &mut [u64]) -> Result<(), crate::Errno> {
+    for slot in out {
+        *slot = monotonic_now_ns()?;
+    }
+    Ok(())
+}
+
+/// Returns the [`Instant`](crate::Instant) of the first call to this function
+/// on the current thread.
+///
+/// Each thread gets its own anchor, so timestamps relative to it are only
+/// comparable within one thread: anchors of different threads differ by
+/// the time between their first calls. In exchange reading it never touches
+/// memory shared with other threads.
+///
+/// # Examples
+///
+/// ```
+/// use unix_clock::{raw::thread_origin, Instant};
+///
+/// let handles = (0..4)
+///     .map(|_| {
+///         std::thread::spawn(|| {
+///             let origin = thread_origin();
+///             let first = Instant::now().duration_since(origin);
+///             let second = Instant::now().duration_since(thread_origin());
+///             assert_eq!(thread_origin(), origin);
+///             assert!(second >= first);
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn thread_origin() -> crate::Instant {
+    std::thread_local! {
+        static ORIGIN: crate::Instant = crate::Instant::now();
+    }
+    ORIGIN.with(|origin| *origin)
+}
+
 impl Timespec {
     #[inline(always)]
     pub const fn zero() -> Self {
         Self::new(0, 0)
     }
 
+    /// Converts an interval to a timespec, as expected by the timer arming and
+    /// relative sleep APIs.
+    ///
+    /// Durations longer than [`Timespec::MAX`] saturate to it, which on every
+    /// platform is far enough in the future to mean "never".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::from_duration(Duration::ZERO), Timespec::zero());
+    /// assert_eq!(
+    ///     Timespec::from_duration(Duration::from_millis(1_500)),
+    ///     Timespec::new(1, 500_000_000)
+    /// );
+    /// assert_eq!(
+    ///     Timespec::from_duration(Duration::from_nanos(999_999_999)),
+    ///     Timespec::new(0, 999_999_999)
+    /// );
+    /// assert_eq!(Timespec::from_duration(Duration::MAX), Timespec::MAX);
+    /// ```
+    #[must_use]
+    pub const fn from_duration(d: Duration) -> Timespec {
+        if d.as_secs() > Self::MAX.secs() as u64 {
+            Self::MAX
+        } else {
+            Self::new(d.as_secs() as i64, d.subsec_nanos())
+        }
+    }
+
     #[inline(always)]
     pub const fn seconds(&self) -> i64 {
         self.secs()
@@ -62,6 +353,154 @@ impl Timespec {
         self.set_nsecs(nsecs)
     }
 
+    /// Returns the seconds and nanoseconds of `self` with the nanoseconds
+    /// carried into the seconds, so that they are always less than 1e9.
+    ///
+    /// [`Timespec::new`] and the setters accept any nanoseconds, this is the
+    /// accessor to use when reading a timespec built by a user. The seconds
+    /// saturate at `i64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(5, 2_500_000_000).normalized_parts(), (7, 500_000_000));
+    /// assert_eq!(Timespec::new(-1, 1_000_000_000).normalized_parts(), (0, 0));
+    /// assert_eq!(Timespec::new(5, 999_999_999).normalized_parts(), (5, 999_999_999));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn normalized_parts(&self) -> (i64, u32) {
+        let nsecs = self.nsecs();
+        (
+            self.secs()
+                .saturating_add((nsecs / NSEC_PER_SEC as u32) as i64),
+            nsecs % NSEC_PER_SEC as u32,
+        )
+    }
+
+    /// Returns `self` as a single count of nanoseconds since the clock's zero.
+    ///
+    /// Lossless: an `i128` holds every [`Timespec`], negative ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::new(1, 500).to_nanos_i128(), 1_000_000_500);
+    /// assert_eq!(Timespec::new(-1, 999_999_999).to_nanos_i128(), -1);
+    /// assert_eq!(
+    ///     Timespec::from_nanos_i128(Timespec::MIN.to_nanos_i128()),
+    ///     Timespec::MIN
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_nanos_i128(&self) -> i128 {
+        self.secs() as i128 * NSEC_PER_SEC as i128 + self.nsecs() as i128
+    }
+
+    /// Creates a timespec from a count of nanoseconds since the clock's zero,
+    /// the inverse of [`Timespec::to_nanos_i128`].
+    ///
+    /// Counts beyond the representable range saturate at [`Timespec::MIN`]
+    /// and [`Timespec::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// assert_eq!(Timespec::from_nanos_i128(-1), Timespec::new(-1, 999_999_999));
+    /// assert_eq!(
+    ///     Timespec::from_nanos_i128(-1_000_000_001),
+    ///     Timespec::new(-2, 999_999_999)
+    /// );
+    /// assert_eq!(Timespec::from_nanos_i128(i128::MAX), Timespec::MAX);
+    /// assert_eq!(Timespec::from_nanos_i128(i128::MIN), Timespec::MIN);
+    /// ```
+    #[must_use]
+    pub const fn from_nanos_i128(nanos: i128) -> Timespec {
+        let secs = nanos.div_euclid(NSEC_PER_SEC as i128);
+        if secs < Self::MIN.secs() as i128 {
+            Self::MIN
+        } else if secs > Self::MAX.secs() as i128 {
+            Self::MAX
+        } else {
+            Self::new(secs as i64, nanos.rem_euclid(NSEC_PER_SEC as i128) as u32)
+        }
+    }
+
+    /// Reinterprets `bytes` as a timespec in the native layout, see
+    /// [`TIMESPEC_SIZE`], e.g. as copied out of a foreign buffer.
+    ///
+    /// With `check_padding`, returns `None` unless
+    /// [`validate_padding`](Timespec::validate_padding) holds, catching
+    /// senders that don't agree on the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::{Timespec, TIMESPEC_SIZE};
+    ///
+    /// assert_eq!(
+    ///     Timespec::from_bytes(&[0; TIMESPEC_SIZE], true),
+    ///     Some(Timespec::zero())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8; TIMESPEC_SIZE], check_padding: bool) -> Option<Timespec> {
+        // SAFETY: a timespec is made of integers only, any bit pattern is
+        // valid, and the read doesn't assume alignment.
+        let t = unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const Timespec) };
+        if check_padding && !t.validate_padding() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    /// Encodes `self` in the crate's stable interchange format: the seconds
+    /// as a little-endian `i64` followed by the nanoseconds as a
+    /// little-endian `u32`, whatever the endianness of the host.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let bytes = Timespec::new(1, 2).to_wire();
+    /// assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+    /// assert_eq!(Timespec::from_wire(&bytes), Some(Timespec::new(1, 2)));
+    /// ```
+    #[must_use]
+    pub fn to_wire(&self) -> [u8; WIRE_SIZE] {
+        let mut bytes = [0; WIRE_SIZE];
+        bytes[..8].copy_from_slice(&self.secs().to_le_bytes());
+        bytes[8..].copy_from_slice(&self.nsecs().to_le_bytes());
+        bytes
+    }
+
+    /// Decodes a timespec encoded by [`Timespec::to_wire`].
+    ///
+    /// Returns `None` if the nanoseconds are not less than 1e9.
+    #[must_use]
+    pub fn from_wire(bytes: &[u8; WIRE_SIZE]) -> Option<Timespec> {
+        let mut secs = [0; 8];
+        secs.copy_from_slice(&bytes[..8]);
+        let mut nsecs = [0; 4];
+        nsecs.copy_from_slice(&bytes[8..]);
+
+        let nsecs = u32::from_le_bytes(nsecs);
+        if nsecs < NSEC_PER_SEC as u32 {
+            Some(Timespec::new(i64::from_le_bytes(secs), nsecs))
+        } else {
+            None
+        }
+    }
+
     pub fn sub_timespec(&self, other: &Timespec) -> Result<Duration, Duration> {
         if self >= other {
             // NOTE(eddyb) two aspects of this `if`-`else` are required for LLVM
@@ -77,14 +516,18 @@ impl Timespec {
             //
             // Ideally this code could be rearranged such that it more
             // directly expresses the lower-cost behavior we want from it.
+            //
+            // The difference between two `i64`s always fits in a `u64` when
+            // `self >= other`, so the subtraction wraps instead of overflowing
+            // for timespecs at the opposite ends of the representable range.
             let (secs, nsecs) = if self.nsecs() >= other.nsecs() {
                 (
-                    (self.secs() - other.secs()) as u64,
+                    self.secs().wrapping_sub(other.secs()) as u64,
                     self.nsecs() - other.nsecs(),
                 )
             } else {
                 (
-                    (self.secs() - other.secs() - 1) as u64,
+                    self.secs().wrapping_sub(other.secs()) as u64 - 1,
                     self.nsecs() + (NSEC_PER_SEC as u32) - other.nsecs(),
                 )
             };
@@ -98,6 +541,40 @@ impl Timespec {
         }
     }
 
+    /// Adds `other` to `self`, returning `None` on overflow.
+    ///
+    /// The nanoseconds of the result are carried into the seconds, so they
+    /// stay below one second even when the sum of both exceeds it. Negative
+    /// timespecs count their nanoseconds forward from their seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let t = Timespec::new(1, 600_000_000);
+    /// assert_eq!(
+    ///     t.checked_add_duration(&Duration::from_millis(700)),
+    ///     Some(Timespec::new(2, 300_000_000))
+    /// );
+    /// // -0.5s + 0.6s
+    /// assert_eq!(
+    ///     Timespec::new(-1, 500_000_000).checked_add_duration(&Duration::from_millis(600)),
+    ///     Some(Timespec::new(0, 100_000_000))
+    /// );
+    /// // the carry alone overflows
+    /// assert_eq!(
+    ///     Timespec::MAX.checked_add_duration(&Duration::from_nanos(1)),
+    ///     None
+    /// );
+    /// let max = Timespec::MAX.secs();
+    /// assert_eq!(
+    ///     Timespec::new(max - 1, 999_999_999).checked_add_duration(&Duration::from_nanos(1)),
+    ///     Some(Timespec::new(max, 0))
+    /// );
+    /// assert_eq!(Timespec::zero().checked_add_duration(&Duration::MAX), None);
+    /// ```
     pub fn checked_add_duration(&self, other: &Duration) -> Option<Timespec> {
         #[inline(always)]
         // fn checked_add_unsigned(a: i64, b: u64) -> Option<i64> {
@@ -124,6 +601,38 @@ impl Timespec {
         Some(Timespec::new(secs, nsecs))
     }
 
+    /// Subtracts `other` from `self`, returning `None` on overflow.
+    ///
+    /// A borrow is taken from the seconds when the nanoseconds of `other`
+    /// exceed those of `self`, so the result stays normalized across zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// // 0.1s - 0.6s
+    /// assert_eq!(
+    ///     Timespec::new(0, 100_000_000).checked_sub_duration(&Duration::from_millis(600)),
+    ///     Some(Timespec::new(-1, 500_000_000))
+    /// );
+    /// assert_eq!(
+    ///     Timespec::new(-1, 0).checked_sub_duration(&Duration::new(1, 1)),
+    ///     Some(Timespec::new(-3, 999_999_999))
+    /// );
+    /// // the borrow alone overflows
+    /// assert_eq!(
+    ///     Timespec::MIN.checked_sub_duration(&Duration::from_nanos(1)),
+    ///     None
+    /// );
+    /// let min = Timespec::MIN.secs();
+    /// assert_eq!(
+    ///     Timespec::new(min + 1, 0).checked_sub_duration(&Duration::from_nanos(1)),
+    ///     Some(Timespec::new(min, 999_999_999))
+    /// );
+    /// assert_eq!(Timespec::zero().checked_sub_duration(&Duration::MAX), None);
+    /// ```
     pub fn checked_sub_duration(&self, other: &Duration) -> Option<Timespec> {
         #[inline(always)]
         // fn checked_sub_unsigned(a: i64, b: u64) -> Option<i64> {
@@ -148,6 +657,47 @@ impl Timespec {
         }
         Some(Timespec::new(secs, nsecs as u32))
     }
+
+    /// Adds `other` to `self`, saturating at [`Timespec::MAX`].
+    ///
+    /// Combined with [`Ord::clamp`] it allows keeping a timespec inside any
+    /// window between [`Timespec::MIN`] and [`Timespec::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let one = Duration::from_nanos(1);
+    /// assert_eq!(Timespec::MAX.checked_add_duration(&one), None);
+    /// assert_eq!(Timespec::MAX.saturating_add_duration(&one), Timespec::MAX);
+    /// assert_eq!(
+    ///     Timespec::new(-5, 0).clamp(Timespec::zero(), Timespec::MAX),
+    ///     Timespec::zero()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn saturating_add_duration(&self, other: &Duration) -> Timespec {
+        self.checked_add_duration(other).unwrap_or(Timespec::MAX)
+    }
+
+    /// Subtracts `other` from `self`, saturating at [`Timespec::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let one = Duration::from_nanos(1);
+    /// assert_eq!(Timespec::MIN.checked_sub_duration(&one), None);
+    /// assert_eq!(Timespec::MIN.saturating_sub_duration(&one), Timespec::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_sub_duration(&self, other: &Duration) -> Timespec {
+        self.checked_sub_duration(other).unwrap_or(Timespec::MIN)
+    }
 }
 
 impl Default for Timespec {
@@ -157,6 +707,79 @@ impl Default for Timespec {
     }
 }
 
+impl core::ops::Add<Duration> for Timespec {
+    type Output = Timespec;
+
+    /// # Panics
+    ///
+    /// Panics if the result would overflow, like `Instant + Duration`. See
+    /// [`Timespec::checked_add_duration`] for a version without panic.
+    #[track_caller]
+    fn add(self, other: Duration) -> Timespec {
+        self.checked_add_duration(&other)
+            .expect("overflow when adding duration to timespec")
+    }
+}
+
+impl core::ops::AddAssign<Duration> for Timespec {
+    /// # Panics
+    ///
+    /// Panics if the result would overflow, like `Instant += Duration`. See
+    /// [`Timespec::checked_add_duration`] for a version without panic.
+    #[track_caller]
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub<Duration> for Timespec {
+    type Output = Timespec;
+
+    /// # Panics
+    ///
+    /// Panics if the result would overflow, like `Instant - Duration`. See
+    /// [`Timespec::checked_sub_duration`] for a version without panic.
+    #[track_caller]
+    fn sub(self, other: Duration) -> Timespec {
+        self.checked_sub_duration(&other)
+            .expect("overflow when subtracting duration from timespec")
+    }
+}
+
+impl core::ops::SubAssign<Duration> for Timespec {
+    /// # Panics
+    ///
+    /// Panics if the result would overflow, like `Instant -= Duration`. See
+    /// [`Timespec::checked_sub_duration`] for a version without panic.
+    #[track_caller]
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Sub<Timespec> for Timespec {
+    type Output = Result<Duration, Duration>;
+
+    /// Returns the distance between the two timespecs, `Err` if `other` is
+    /// later than `self`, see [`Timespec::sub_timespec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::raw::Timespec;
+    ///
+    /// let a = Timespec::new(2, 0);
+    /// let b = Timespec::new(1, 500_000_000);
+    /// assert_eq!(a - b, Ok(Duration::from_millis(500)));
+    /// assert_eq!(b - a, Err(Duration::from_millis(500)));
+    /// ```
+    #[inline]
+    fn sub(self, other: Timespec) -> Result<Duration, Duration> {
+        self.sub_timespec(&other)
+    }
+}
+
 impl fmt::Debug for Timespec {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Timespec")
@@ -175,17 +798,14 @@ impl PartialEq for Timespec {
 impl Eq for Timespec {}
 
 impl PartialOrd for Timespec {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.secs().partial_cmp(&other.secs()) {
-            Some(core::cmp::Ordering::Equal) => (),
-            ord => return ord,
-        }
-        self.nsecs().partial_cmp(&other.nsecs())
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Timespec {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         match self.secs().cmp(&other.secs()) {
             core::cmp::Ordering::Equal => (),
             ord => return ord,
@@ -195,8 +815,26 @@ impl Ord for Timespec {
 }
 
 impl core::hash::Hash for Timespec {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.secs().hash(state);
         self.nsecs().hash(state);
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timespec {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(
+            u.int_in_range(Self::MIN.secs()..=Self::MAX.secs())?,
+            u.int_in_range(0..=(NSEC_PER_SEC as u32 - 1))?,
+        ))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <i64 as arbitrary::Arbitrary>::size_hint(depth),
+            <u32 as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
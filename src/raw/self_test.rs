@@ -0,0 +1,61 @@
+use core::time::Duration;
+
+use super::{monotonic_now_ns, using_vdso};
+use crate::Errno;
+
+/// Number of clock reads timed by [`self_test`].
+pub const SELF_TEST_READS: usize = 1024;
+
+/// Median read latency above which [`self_test`] flags the clock as slow.
+///
+/// A vdso read takes a few tens of nanoseconds, a real syscall hundreds.
+pub const SLOW_READ_THRESHOLD: Duration = Duration::from_nanos(500);
+
+/// Clock read latencies measured by [`self_test`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelfTestReport {
+    /// Median latency of a monotonic clock read.
+    pub median: Duration,
+    /// Worst latency of a monotonic clock read.
+    pub max: Duration,
+    /// Whether reads go through the vdso, see [`using_vdso`].
+    pub vdso: bool,
+    /// Whether the median latency exceeds [`SLOW_READ_THRESHOLD`], usually a
+    /// sign of a missing vdso or of a clock source the vdso can't read.
+    pub slow: bool,
+}
+
+/// Times [`SELF_TEST_READS`] consecutive monotonic clock reads.
+///
+/// Meant to be logged at startup to catch environments where reading the
+/// clock is unexpectedly expensive.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::raw::self_test;
+///
+/// let report = self_test().unwrap();
+/// assert!(report.median <= report.max);
+/// assert!(report.median < Duration::from_millis(1));
+/// println!("{:?}", report);
+/// ```
+pub fn self_test() -> Result<SelfTestReport, Errno> {
+    let mut samples = [0u64; SELF_TEST_READS];
+    let mut prev = monotonic_now_ns()?;
+    for sample in samples.iter_mut() {
+        let now = monotonic_now_ns()?;
+        *sample = now.wrapping_sub(prev);
+        prev = now;
+    }
+    samples.sort_unstable();
+
+    let median = Duration::from_nanos(samples[SELF_TEST_READS / 2]);
+    Ok(SelfTestReport {
+        median,
+        max: Duration::from_nanos(samples[SELF_TEST_READS - 1]),
+        vdso: using_vdso(),
+        slow: median > SLOW_READ_THRESHOLD,
+    })
+}
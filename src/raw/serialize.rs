@@ -0,0 +1,162 @@
+use core::fmt;
+
+use serde::{
+    de::{self, Error as _, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{Timespec, NSEC_PER_SEC};
+use crate::{Instant, SystemTime};
+
+// Human-readable formats get the `{ "secs": .., "nsecs": .. }` struct. Compact
+// ones get the nanoseconds since the zero of the clock as one `i128`, which
+// has a single encoding per timestamp and needs neither `std` nor `alloc`:
+// with `postcard` a timestamp takes at most 14 bytes, with `bincode` 16.
+
+const FIELDS: &[&str] = &["secs", "nsecs"];
+
+const MIN_NANOS: i128 = Timespec::MIN.to_nanos_i128();
+const MAX_NANOS: i128 = Timespec::MAX.to_nanos_i128();
+
+/// Serializes as a `{ secs, nsecs }` struct in human-readable formats, e.g.
+/// JSON, and as the nanoseconds since the zero of the clock, a single `i128`,
+/// in compact ones, e.g. `postcard` or `bincode`.
+///
+/// Neither needs `std` or `alloc`: timestamps serialize into a stack buffer
+/// with `postcard`.
+impl Serialize for Timespec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Timespec", 2)?;
+            state.serialize_field("secs", &self.secs())?;
+            state.serialize_field("nsecs", &self.nsecs())?;
+            state.end()
+        } else {
+            self.to_nanos_i128().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timespec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            let nanos = i128::deserialize(deserializer)?;
+            return if (MIN_NANOS..=MAX_NANOS).contains(&nanos) {
+                Ok(Timespec::from_nanos_i128(nanos))
+            } else {
+                Err(D::Error::custom("timestamp out of range"))
+            };
+        }
+
+        let (secs, nsecs) = deserializer.deserialize_struct("Timespec", FIELDS, TimespecVisitor)?;
+        if nsecs < NSEC_PER_SEC as u32 {
+            Ok(Timespec::new(secs, nsecs))
+        } else {
+            Err(D::Error::custom("nanoseconds out of range"))
+        }
+    }
+}
+
+enum Field {
+    Secs,
+    Nsecs,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("`secs` or `nsecs`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "secs" => Ok(Field::Secs),
+                    "nsecs" => Ok(Field::Nsecs),
+                    _ => Err(E::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct TimespecVisitor;
+
+impl<'de> Visitor<'de> for TimespecVisitor {
+    type Value = (i64, u32);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("struct Timespec")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let secs = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let nsecs = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok((secs, nsecs))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut secs = None;
+        let mut nsecs = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Secs if secs.is_some() => return Err(A::Error::duplicate_field("secs")),
+                Field::Secs => secs = Some(map.next_value()?),
+                Field::Nsecs if nsecs.is_some() => return Err(A::Error::duplicate_field("nsecs")),
+                Field::Nsecs => nsecs = Some(map.next_value()?),
+            }
+        }
+        Ok((
+            secs.ok_or_else(|| A::Error::missing_field("secs"))?,
+            nsecs.ok_or_else(|| A::Error::missing_field("nsecs"))?,
+        ))
+    }
+}
+
+/// Serializes like [`Timespec`], counting from the Unix epoch: a
+/// `{ secs, nsecs }` struct in human-readable formats, the `i128` nanoseconds
+/// since the epoch in compact ones.
+impl Serialize for SystemTime {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.t.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemTime {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Timespec::deserialize(deserializer).map(|t| Self { t })
+    }
+}
+
+/// Serializes like [`SystemTime`], counting from the zero of the monotonic
+/// clock instead of the Unix epoch.
+///
+/// That zero is usually the boot of the system, so a serialized instant is
+/// only meaningful within the boot it was read in: after a reboot, or on
+/// another machine, it deserializes to an unrelated instant.
+impl Serialize for Instant {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.t.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Instant {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Timespec::deserialize(deserializer).map(|t| Self { t })
+    }
+}
@@ -0,0 +1,278 @@
+//! POSIX per-process interval timers, see `timer_create(2)`.
+//!
+//! Unlike `timerfd`, they are available beyond Linux: on FreeBSD, DragonFly
+//! and NetBSD too. macOS, the other Apple platforms and OpenBSD don't
+//! implement `timer_create`, and this crate provides no fallback there:
+//! [`PosixTimer`] is not available on them, the `EVFILT_TIMER` filter of
+//! `kqueue(2)` being the closest native equivalent.
+//!
+//! Only Linux delivers an expiration to a given thread, with
+//! `SigevNotify::ThreadSignal`; elsewhere the signal goes to the process.
+
+use super::{AsRawClockId, Timespec};
+use crate::Errno;
+
+/// Initial expiration and reload interval of a timer, laid out as the
+/// `struct itimerspec` of the platform.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Itimerspec {
+    /// The period of the timer after its first expiration, zero for a
+    /// one-shot timer.
+    pub interval: Timespec,
+    /// The time until (or, with an absolute setting, the time of) the next
+    /// expiration, zero for a disarmed timer.
+    pub value: Timespec,
+}
+
+/// How a [`PosixTimer`] notifies its expirations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SigevNotify {
+    /// No notification, expirations are observed with
+    /// [`PosixTimer::get_time`] and [`PosixTimer::overrun`].
+    None,
+    /// The signal `signo` is sent to the process.
+    Signal(i32),
+    /// The signal `signo` is sent to the thread `tid`, which must belong to
+    /// the calling process (`SIGEV_THREAD_ID`, Linux-specific).
+    #[cfg(target_os = "linux")]
+    ThreadSignal {
+        /// The signal to send.
+        signo: i32,
+        /// The kernel id of the thread to notify, as returned by `gettid(2)`.
+        tid: i32,
+    },
+}
+
+/// A POSIX interval timer, deleted on drop.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread::sleep, time::Duration};
+/// use unix_clock::raw::{ClockId, Itimerspec, PosixTimer, SigevNotify, Timespec};
+///
+/// let timer = PosixTimer::new(ClockId::Monotonic, SigevNotify::None).unwrap();
+/// // one-shot, firing in 10ms
+/// timer
+///     .set_time(
+///         &Itimerspec {
+///             interval: Timespec::zero(),
+///             value: Timespec::from_duration(Duration::from_millis(10)),
+///         },
+///         false,
+///     )
+///     .unwrap();
+/// assert_ne!(timer.get_time().unwrap().value, Timespec::zero());
+///
+/// sleep(Duration::from_millis(20));
+/// // fired and not rearmed
+/// assert_eq!(timer.get_time().unwrap(), Itimerspec::default());
+/// ```
+#[derive(Debug)]
+pub struct PosixTimer {
+    id: sys::TimerId,
+}
+
+impl PosixTimer {
+    /// Creates a disarmed timer measuring time with `clock`, a
+    /// [`ClockId`](super::ClockId) or, on Linux, a
+    /// [`DynamicClock`](super::DynamicClock).
+    #[inline]
+    pub fn new<C: AsRawClockId>(clock: C, notify: SigevNotify) -> Result<Self, Errno> {
+        sys::create(clock.as_raw_clock_id(), notify).map(|id| Self { id })
+    }
+
+    /// Arms the timer with `spec`, or disarms it if `spec.value` is zero.
+    ///
+    /// With `absolute` the first expiration is at the time `spec.value` of the
+    /// timer's clock, otherwise `spec.value` after now.
+    #[inline]
+    pub fn set_time(&self, spec: &Itimerspec, absolute: bool) -> Result<(), Errno> {
+        sys::set_time(self.id, spec, absolute)
+    }
+
+    /// Returns the time until the next expiration, zero if the timer is
+    /// disarmed, and the reload interval.
+    #[inline]
+    pub fn get_time(&self) -> Result<Itimerspec, Errno> {
+        sys::get_time(self.id)
+    }
+
+    /// Returns the number of expirations that happened while the notification
+    /// of the previous one was pending.
+    #[inline]
+    pub fn overrun(&self) -> Result<u32, Errno> {
+        sys::overrun(self.id)
+    }
+}
+
+impl Drop for PosixTimer {
+    #[inline]
+    fn drop(&mut self) {
+        sys::delete(self.id);
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use cfg_if::cfg_if;
+    use linux_syscalls::{syscall, Errno, Sysno};
+
+    use super::{Itimerspec, SigevNotify};
+
+    cfg_if! {
+        if #[cfg(any(target_arch = "x86_64", target_pointer_width = "64"))] {
+            #[allow(non_upper_case_globals)]
+            const SYS_timer_settime: Sysno = Sysno::timer_settime;
+            #[allow(non_upper_case_globals)]
+            const SYS_timer_gettime: Sysno = Sysno::timer_gettime;
+        } else {
+            #[allow(non_upper_case_globals)]
+            const SYS_timer_settime: Sysno = Sysno::timer_settime64;
+            #[allow(non_upper_case_globals)]
+            const SYS_timer_gettime: Sysno = Sysno::timer_gettime64;
+        }
+    }
+
+    const SIGEV_SIGNAL: i32 = 0;
+    const SIGEV_NONE: i32 = 1;
+    const SIGEV_THREAD_ID: i32 = 4;
+    const TIMER_ABSTIME: usize = 1;
+
+    const SIGEVENT_PADDING: usize = (64 - 3 * 4 - core::mem::size_of::<usize>()) / 4;
+
+    // The kernel's `struct sigevent`, padded to 64 bytes.
+    #[repr(C)]
+    struct Sigevent {
+        sigev_value: usize,
+        sigev_signo: i32,
+        sigev_notify: i32,
+        sigev_tid: i32,
+        __padding: [i32; SIGEVENT_PADDING],
+    }
+
+    pub type TimerId = i32;
+
+    pub fn create(clock: i32, notify: SigevNotify) -> Result<TimerId, Errno> {
+        let (sigev_notify, sigev_signo, sigev_tid) = match notify {
+            SigevNotify::None => (SIGEV_NONE, 0, 0),
+            SigevNotify::Signal(signo) => (SIGEV_SIGNAL, signo, 0),
+            SigevNotify::ThreadSignal { signo, tid } => (SIGEV_THREAD_ID, signo, tid),
+        };
+        let sev = Sigevent {
+            sigev_value: 0,
+            sigev_signo,
+            sigev_notify,
+            sigev_tid,
+            __padding: [0; SIGEVENT_PADDING],
+        };
+        let mut id: TimerId = 0;
+        unsafe {
+            syscall!(
+                Sysno::timer_create,
+                clock,
+                &sev as *const Sigevent,
+                &mut id as *mut TimerId
+            )
+        }
+        .map(|_| id)
+    }
+
+    #[inline]
+    pub fn set_time(id: TimerId, spec: &Itimerspec, absolute: bool) -> Result<(), Errno> {
+        let flags = if absolute { TIMER_ABSTIME } else { 0 };
+        unsafe {
+            syscall!(
+                SYS_timer_settime,
+                id,
+                flags,
+                spec as *const Itimerspec,
+                core::ptr::null_mut::<Itimerspec>()
+            )
+        }
+        .map(|_| ())
+    }
+
+    #[inline]
+    pub fn get_time(id: TimerId) -> Result<Itimerspec, Errno> {
+        let mut spec = Itimerspec::default();
+        unsafe { syscall!(SYS_timer_gettime, id, &mut spec as *mut Itimerspec) }.map(|_| spec)
+    }
+
+    #[inline]
+    pub fn overrun(id: TimerId) -> Result<u32, Errno> {
+        unsafe { syscall!([ro] Sysno::timer_getoverrun, id) }.map(|n| n as u32)
+    }
+
+    #[inline]
+    pub fn delete(id: TimerId) {
+        let _ = unsafe { syscall!(Sysno::timer_delete, id) };
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use core::mem::MaybeUninit;
+
+    use super::{Itimerspec, SigevNotify};
+    use crate::Errno;
+
+    pub type TimerId = libc::timer_t;
+
+    #[inline]
+    fn check(ret: libc::c_int) -> Result<libc::c_int, Errno> {
+        if ret == -1 {
+            Err(Errno::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+
+    pub fn create(clock: libc::clockid_t, notify: SigevNotify) -> Result<TimerId, Errno> {
+        let mut sev: libc::sigevent = unsafe { core::mem::zeroed() };
+        match notify {
+            SigevNotify::None => sev.sigev_notify = libc::SIGEV_NONE,
+            SigevNotify::Signal(signo) => {
+                sev.sigev_notify = libc::SIGEV_SIGNAL;
+                sev.sigev_signo = signo;
+            }
+        }
+        let mut id = MaybeUninit::<TimerId>::uninit();
+        check(unsafe { libc::timer_create(clock, &mut sev, id.as_mut_ptr()) })?;
+        Ok(unsafe { id.assume_init() })
+    }
+
+    #[inline]
+    pub fn set_time(id: TimerId, spec: &Itimerspec, absolute: bool) -> Result<(), Errno> {
+        let flags = if absolute { libc::TIMER_ABSTIME } else { 0 };
+        check(unsafe {
+            libc::timer_settime(
+                id,
+                flags,
+                spec as *const Itimerspec as *const libc::itimerspec,
+                core::ptr::null_mut(),
+            )
+        })
+        .map(|_| ())
+    }
+
+    #[inline]
+    pub fn get_time(id: TimerId) -> Result<Itimerspec, Errno> {
+        let mut spec = Itimerspec::default();
+        check(unsafe {
+            libc::timer_gettime(id, &mut spec as *mut Itimerspec as *mut libc::itimerspec)
+        })
+        .map(|_| spec)
+    }
+
+    #[inline]
+    pub fn overrun(id: TimerId) -> Result<u32, Errno> {
+        check(unsafe { libc::timer_getoverrun(id) }).map(|n| n as u32)
+    }
+
+    #[inline]
+    pub fn delete(id: TimerId) {
+        unsafe { libc::timer_delete(id) };
+    }
+}
@@ -0,0 +1,96 @@
+//! Temporary changes of the system clock, undone on drop.
+
+use super::{ClockId, Timespec, BOOT_INSTANT_CLOCKID};
+use crate::Errno;
+
+/// Restores the system clock when dropped, even while unwinding from a
+/// panic, after it has been changed with [`ClockTransaction::set`].
+///
+/// The clock is set back to the time read when the transaction began, plus
+/// the real time elapsed since, as measured by the clock of
+/// [`BootInstant`](crate::BootInstant): it keeps counting if the machine is
+/// suspended meanwhile.
+///
+/// Setting the clock requires privileges, e.g. `CAP_SYS_TIME` on Linux.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use unix_clock::raw::{ClockTransaction, Timespec};
+///
+/// let mut transaction = ClockTransaction::begin().unwrap();
+/// transaction
+///     .set(&Timespec::from_duration(Duration::from_secs(2_000_000_000)))
+///     .unwrap();
+/// // ... test code running far in the future ...
+/// drop(transaction); // back to the present
+/// ```
+#[derive(Debug)]
+#[must_use = "the system clock is restored as soon as the transaction is dropped"]
+pub struct ClockTransaction {
+    realtime: Timespec,
+    started: Timespec,
+    changed: bool,
+}
+
+impl ClockTransaction {
+    /// Records the current system time, to be restored on drop.
+    pub fn begin() -> Result<Self, Errno> {
+        Ok(Self {
+            started: Timespec::now(BOOT_INSTANT_CLOCKID)?,
+            realtime: Timespec::now(ClockId::Realtime)?,
+            changed: false,
+        })
+    }
+
+    /// Sets the system clock to `t`.
+    ///
+    /// Without the privileges to set the clock, the clock is left untouched
+    /// and `Err(Errno::EPERM)` is returned, so tests can tell they must be
+    /// skipped. With the `std` feature a warning is printed too.
+    pub fn set(&mut self, t: &Timespec) -> Result<(), Errno> {
+        match t.set_clock() {
+            Ok(()) => {
+                self.changed = true;
+                Ok(())
+            }
+            Err(errno) => {
+                #[cfg(feature = "std")]
+                if errno == Errno::EPERM {
+                    std::eprintln!(
+                        "warning: not permitted to set the system clock, left untouched"
+                    );
+                }
+                Err(errno)
+            }
+        }
+    }
+
+    /// Returns the time the system clock is restored to if the transaction
+    /// is dropped now.
+    pub fn restore_time(&self) -> Result<Timespec, Errno> {
+        let elapsed = Timespec::now(BOOT_INSTANT_CLOCKID)?
+            .sub_timespec(&self.started)
+            .unwrap_or_default();
+        Ok(self.realtime.saturating_add_duration(&elapsed))
+    }
+}
+
+impl Drop for ClockTransaction {
+    fn drop(&mut self) {
+        if !self.changed {
+            return;
+        }
+        let res = self.restore_time().and_then(|t| t.set_clock());
+        #[cfg(feature = "std")]
+        if let Err(errno) = res {
+            std::eprintln!(
+                "warning: failed to restore the system clock: errno {}",
+                errno.into_raw()
+            );
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = res;
+    }
+}
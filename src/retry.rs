@@ -0,0 +1,99 @@
+use core::time::Duration;
+
+use crate::{raw, sleep_until, Instant};
+
+// Cap of the exponent, so the delay computation never overflows.
+const MAX_DOUBLINGS: u32 = 31;
+
+/// Calls `f` until it succeeds or `deadline` passes, sleeping with
+/// exponential backoff between attempts.
+///
+/// The n-th retry waits a random delay between half and all of `base * 2^n`,
+/// never past `deadline`. The jitter keeps clients that failed together from
+/// retrying in lockstep.
+///
+/// `f` is always called at least once. Returns the first success, or the
+/// error of the last attempt once the deadline has passed.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{retry_with_backoff, Instant};
+///
+/// // succeeds on the 3rd try
+/// let mut attempts = 0;
+/// let deadline = Instant::now() + Duration::from_secs(10);
+/// let res = retry_with_backoff(deadline, Duration::from_millis(1), || {
+///     attempts += 1;
+///     if attempts < 3 {
+///         Err(attempts)
+///     } else {
+///         Ok(attempts)
+///     }
+/// });
+/// assert_eq!(res, Ok(3));
+///
+/// // the deadline is hit first
+/// let mut attempts = 0;
+/// let deadline = Instant::now() + Duration::from_millis(20);
+/// let res: Result<(), _> = retry_with_backoff(deadline, Duration::from_millis(1), || {
+///     attempts += 1;
+///     Err(attempts)
+/// });
+/// assert_eq!(res, Err(attempts));
+/// assert!(attempts > 1);
+/// assert!(Instant::now() >= deadline);
+/// ```
+pub fn retry_with_backoff<T, E>(
+    deadline: Instant,
+    base: Duration,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut rng = XorShift::from_clock();
+    let mut doublings = 0;
+    loop {
+        let err = match f() {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+
+        let now = Instant::now();
+        let left = match deadline.checked_duration_since(now) {
+            Some(left) if !left.is_zero() => left,
+            _ => return Err(err),
+        };
+
+        let delay = base.saturating_mul(1 << doublings);
+        doublings = (doublings + 1).min(MAX_DOUBLINGS);
+        let half = delay / 2;
+        let jitter = match u64::try_from((delay - half).as_nanos()) {
+            Ok(0) => 0,
+            Ok(max) => rng.next_u64() % max,
+            Err(_) => rng.next_u64(),
+        };
+        // Backoff delays don't need better than the scheduler's accuracy: the
+        // thread sleeps instead of spinning. Should the sleep fail, the next
+        // attempt just comes early.
+        let _ = sleep_until(now + (half + Duration::from_nanos(jitter)).min(left));
+    }
+}
+
+/// xorshift64, good enough for jitter and free of dependencies.
+struct XorShift(u64);
+
+impl XorShift {
+    #[inline]
+    fn from_clock() -> Self {
+        // The state must never be zero.
+        Self(raw::monotonic_now_ns().unwrap_or(0) | 1)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
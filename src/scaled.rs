@@ -0,0 +1,125 @@
+use crate::{raw, Instant};
+
+/// A virtual clock running a rational factor faster or slower than
+/// [`Instant`], e.g. to drive a simulation.
+///
+/// The virtual time is the anchor plus the real time elapsed since, scaled by
+/// `num / den`. The scale is kept as integers, so the virtual time doesn't
+/// accumulate floating point rounding, and changing it with
+/// [`ScaledClock::set_scale`] re-anchors the clock: the virtual time never
+/// jumps.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{Instant, ScaledClock};
+///
+/// let start = Instant::now();
+/// let mut clock = ScaledClock::new_at(start, 2, 1);
+/// let real = start + Duration::from_secs(1);
+/// assert_eq!(clock.now_at(real), start + Duration::from_secs(2));
+///
+/// // slow down to half speed from there
+/// clock.set_scale_at(real, 1, 2);
+/// let later = real + Duration::from_secs(1);
+/// assert_eq!(clock.now_at(later), start + Duration::from_millis(2_500));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaledClock {
+    anchor_real: Instant,
+    anchor_virtual: i128,
+    num: u32,
+    den: u32,
+}
+
+impl ScaledClock {
+    /// Creates a clock reading the current instant now and running
+    /// `num / den` times as fast as [`Instant`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    #[inline]
+    pub fn new(num: u32, den: u32) -> Self {
+        Self::new_at(Instant::now(), num, den)
+    }
+
+    /// Creates a clock reading `real` at `real` and running `num / den`
+    /// times as fast as [`Instant`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn new_at(real: Instant, num: u32, den: u32) -> Self {
+        assert!(den != 0, "scale with a zero denominator");
+        Self {
+            anchor_real: real,
+            anchor_virtual: real.t.to_nanos_i128(),
+            num,
+            den,
+        }
+    }
+
+    /// Returns the scale as a `(num, den)` fraction.
+    #[must_use]
+    #[inline]
+    pub const fn scale(&self) -> (u32, u32) {
+        (self.num, self.den)
+    }
+
+    /// Returns the virtual time corresponding to "now".
+    #[must_use]
+    #[inline]
+    pub fn now(&self) -> Instant {
+        self.now_at(Instant::now())
+    }
+
+    /// Returns the virtual time at the real instant `real`.
+    ///
+    /// Instants before the anchor are scaled backwards. The virtual time
+    /// saturates at the bounds of [`Instant`].
+    #[must_use]
+    pub fn now_at(&self, real: Instant) -> Instant {
+        Instant {
+            t: raw::Timespec::from_nanos_i128(self.virtual_nanos(real)),
+        }
+    }
+
+    /// Changes the scale from now on, see [`ScaledClock::set_scale_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    #[inline]
+    pub fn set_scale(&mut self, num: u32, den: u32) {
+        self.set_scale_at(Instant::now(), num, den)
+    }
+
+    /// Changes the scale to `num / den` from the real instant `real`, the
+    /// virtual time up to it being kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    pub fn set_scale_at(&mut self, real: Instant, num: u32, den: u32) {
+        assert!(den != 0, "scale with a zero denominator");
+        self.anchor_virtual = self.now_at(real).t.to_nanos_i128();
+        self.anchor_real = real;
+        self.num = num;
+        self.den = den;
+    }
+
+    #[inline]
+    fn virtual_nanos(&self, real: Instant) -> i128 {
+        let elapsed = real.t.to_nanos_i128() - self.anchor_real.t.to_nanos_i128();
+        // Both anchors are within the bounds of a timespec, so |elapsed| < 2^95
+        // and neither the product nor the sum overflows.
+        self.anchor_virtual + elapsed * self.num as i128 / self.den as i128
+    }
+}
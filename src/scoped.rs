@@ -0,0 +1,112 @@
+use core::time::Duration;
+
+use crate::Instant;
+
+/// Times the scope it lives in, reporting the elapsed time when dropped.
+///
+/// The report is either a callback, see [`ScopedTimer::new`], or an addition
+/// to a nanosecond counter, see [`ScopedTimer::accumulate`].
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, thread::sleep, time::Duration};
+/// use unix_clock::ScopedTimer;
+///
+/// let elapsed = Cell::new(Duration::ZERO);
+/// {
+///     let _timer = ScopedTimer::new(|d| elapsed.set(d));
+///     sleep(Duration::from_millis(10));
+/// }
+/// assert!(elapsed.get() >= Duration::from_millis(10));
+/// ```
+#[must_use = "the scope is timed until the timer is dropped"]
+pub struct ScopedTimer<'a, F: FnOnce(Duration) = fn(Duration)> {
+    start: Instant,
+    report: Option<Report<'a, F>>,
+}
+
+enum Report<'a, F> {
+    Callback(F),
+    #[cfg(target_has_atomic = "64")]
+    Nanos(&'a core::sync::atomic::AtomicU64),
+    #[cfg(not(target_has_atomic = "64"))]
+    #[allow(dead_code)]
+    Never(core::marker::PhantomData<&'a ()>),
+}
+
+impl<'a, F: FnOnce(Duration)> ScopedTimer<'a, F> {
+    /// Starts a timer calling `report` with the elapsed time when dropped.
+    #[inline]
+    pub fn new(report: F) -> Self {
+        Self {
+            start: Instant::now(),
+            report: Some(Report::Callback(report)),
+        }
+    }
+
+    /// Returns the time elapsed since the timer started.
+    #[must_use]
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        Instant::now().saturating_duration_since(self.start)
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<'a> ScopedTimer<'a> {
+    /// Starts a timer adding the elapsed nanoseconds to `total` when dropped.
+    ///
+    /// The counter wraps on overflow, after ~584 years of accumulated time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{
+    ///     sync::atomic::{AtomicU64, Ordering},
+    ///     thread::sleep,
+    ///     time::Duration,
+    /// };
+    /// use unix_clock::ScopedTimer;
+    ///
+    /// static TOTAL: AtomicU64 = AtomicU64::new(0);
+    ///
+    /// for _ in 0..2 {
+    ///     let _timer = ScopedTimer::accumulate(&TOTAL);
+    ///     sleep(Duration::from_millis(5));
+    /// }
+    /// assert!(TOTAL.load(Ordering::Relaxed) >= 10_000_000);
+    /// ```
+    #[inline]
+    pub fn accumulate(total: &'a core::sync::atomic::AtomicU64) -> Self {
+        Self {
+            start: Instant::now(),
+            report: Some(Report::Nanos(total)),
+        }
+    }
+}
+
+impl<'a, F: FnOnce(Duration)> Drop for ScopedTimer<'a, F> {
+    fn drop(&mut self) {
+        let elapsed = self.elapsed();
+        match self.report.take() {
+            Some(Report::Callback(report)) => report(elapsed),
+            #[cfg(target_has_atomic = "64")]
+            Some(Report::Nanos(total)) => {
+                let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+                total.fetch_add(nanos, core::sync::atomic::Ordering::Relaxed);
+            }
+            #[cfg(not(target_has_atomic = "64"))]
+            Some(Report::Never(_)) => (),
+            None => (),
+        }
+    }
+}
+
+impl<'a, F: FnOnce(Duration)> core::fmt::Debug for ScopedTimer<'a, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScopedTimer")
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
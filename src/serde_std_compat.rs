@@ -0,0 +1,35 @@
+//! Serializes [`SystemTime`] exactly like serde serializes
+//! [`std::time::SystemTime`], for use with `#[serde(with = "...")]`.
+//!
+//! The representation is the `SystemTime { secs_since_epoch,
+//! nanos_since_epoch }` struct, so data written with one type reads back as
+//! the other, e.g. while migrating a codebase from `std::time`. Like std's,
+//! it can't represent times before the [`UNIX_EPOCH`](SystemTime::UNIX_EPOCH):
+//! serializing them fails.
+//!
+//! Requires the `serde` and `std` features.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Entry {
+//!     #[serde(with = "unix_clock::serde_std_compat")]
+//!     modified: unix_clock::SystemTime,
+//! }
+//! ```
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::SystemTime;
+
+/// Serializes `t` as serde serializes the equivalent [`std::time::SystemTime`].
+pub fn serialize<S: Serializer>(t: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+    std::time::SystemTime::from(*t).serialize(serializer)
+}
+
+/// Deserializes a [`std::time::SystemTime`] as serialized by serde.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+    SystemTime::try_from(std::time::SystemTime::deserialize(deserializer)?)
+        .map_err(D::Error::custom)
+}
@@ -0,0 +1,43 @@
+/// Declares a module mirroring `std::time`, with its [`Instant`](crate::Instant)
+/// and [`SystemTime`](crate::SystemTime) replaced by this crate's.
+///
+/// Rust has no supported way to override the clock behind
+/// `std::time::Instant::now`: its symbols are not weak, and interposing the
+/// libc `clock_gettime` would affect every other library of the process. The
+/// drop-in path is instead to import the time types from the generated module
+/// in place of `std::time`, leaving the rest of the code untouched.
+///
+/// The module is named `time` unless a name, optionally preceded by a
+/// visibility, is given. Everything else, e.g. `Duration`, is re-exported from
+/// `std::time`. Values of the replaced types can't be passed to APIs that
+/// expect the `std` ones; under the `std` feature `SystemTime` converts to
+/// std's with `From` and back with `TryFrom`.
+///
+/// # Examples
+///
+/// ```
+/// unix_clock::replace_std_time!();
+///
+/// // was `use std::time::{Duration, Instant};`
+/// use time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// std::thread::sleep(Duration::from_millis(10));
+/// assert!(start.elapsed() >= Duration::from_millis(10));
+/// assert!(time::SystemTime::now() > time::UNIX_EPOCH);
+/// ```
+#[macro_export]
+macro_rules! replace_std_time {
+    () => {
+        $crate::replace_std_time!(time);
+    };
+    ($vis:vis $name:ident) => {
+        $vis mod $name {
+            pub use ::std::time::*;
+            pub use $crate::{Instant, SystemTime, SystemTimeError};
+
+            /// An anchor in time, see `std::time::UNIX_EPOCH`.
+            pub const UNIX_EPOCH: SystemTime = SystemTime::UNIX_EPOCH;
+        }
+    };
+}
@@ -0,0 +1,91 @@
+use core::{fmt, time::Duration};
+
+/// A span of time that can be negative, as returned by
+/// [`SystemTime::offset_from`](crate::SystemTime::offset_from).
+///
+/// Spans up to [`Duration::MAX`] in either direction are representable.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::SignedDuration;
+///
+/// let behind = SignedDuration::new(true, Duration::from_millis(1_500));
+/// assert!(behind.is_negative());
+/// assert_eq!(behind.as_nanos(), -1_500_000_000);
+/// assert_eq!(behind.unsigned_abs(), Duration::from_millis(1_500));
+/// assert_eq!(-behind, SignedDuration::from(Duration::from_millis(1_500)));
+/// assert!(behind < SignedDuration::ZERO);
+/// assert_eq!(format!("{:?}", behind), "-1.5s");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedDuration {
+    nanos: i128,
+}
+
+impl SignedDuration {
+    /// A span of zero length.
+    pub const ZERO: Self = Self { nanos: 0 };
+
+    /// Creates a span of length `magnitude`, going backwards in time if
+    /// `negative` is `true`.
+    #[must_use]
+    #[inline]
+    pub const fn new(negative: bool, magnitude: Duration) -> Self {
+        let nanos = magnitude.as_nanos() as i128;
+        Self {
+            nanos: if negative { -nanos } else { nanos },
+        }
+    }
+
+    /// Returns `true` if the span goes backwards in time.
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// Returns the length of the span.
+    #[must_use]
+    #[inline]
+    pub const fn unsigned_abs(&self) -> Duration {
+        let nanos = self.nanos.unsigned_abs();
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Returns the span as a signed count of nanoseconds.
+    #[must_use]
+    #[inline]
+    pub const fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    #[inline]
+    fn from(value: Duration) -> Self {
+        Self::new(false, value)
+    }
+}
+
+impl core::ops::Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    #[inline]
+    fn neg(self) -> SignedDuration {
+        Self { nanos: -self.nanos }
+    }
+}
+
+impl fmt::Debug for SignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            f.write_str("-")?;
+        }
+        fmt::Debug::fmt(&self.unsigned_abs(), f)
+    }
+}
@@ -0,0 +1,125 @@
+use core::time::Duration;
+
+use crate::{raw, Errno, Instant};
+
+/// Default tail of a [`precise_sleep`] that is busy-waited instead of slept.
+///
+/// It covers the default Linux timer slack (50µs) plus typical scheduler
+/// wake-up latency.
+pub const DEFAULT_SPIN_THRESHOLD: Duration = Duration::from_micros(200);
+
+/// Puts the current thread to sleep until `deadline`.
+///
+/// The thread sleeps on the clock of [`Instant`] with an absolute
+/// `clock_nanosleep(2)`, so a fixed-rate loop advancing its deadline by a
+/// period doesn't accumulate the drift of repeated relative sleeps. Where
+/// `clock_nanosleep` is missing the remaining time is slept with
+/// `nanosleep(2)` instead. Interruptions by signal handlers are retried until the deadline
+/// passes; a deadline in the past returns immediately.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{sleep_until, Instant};
+///
+/// let start = Instant::now();
+/// let mut deadline = start;
+/// for _ in 0..5 {
+///     deadline += Duration::from_millis(2);
+///     sleep_until(deadline).unwrap();
+/// }
+/// assert!(Instant::now() >= start + Duration::from_millis(10));
+/// ```
+pub fn sleep_until(deadline: Instant) -> Result<(), Errno> {
+    let deadline = raw::Timespec::from(deadline);
+    loop {
+        match deadline.sleep_until(raw::INSTANT_CLOCKID) {
+            Err(Errno::EINTR) => continue,
+            res => return res,
+        }
+    }
+}
+
+/// Puts the current thread to sleep for exactly `d`, as far as the clock
+/// permits.
+///
+/// Equivalent to [`precise_sleep_with_threshold`] with
+/// [`DEFAULT_SPIN_THRESHOLD`].
+///
+/// # Panics
+///
+/// This function may panic if `Instant::now() + d` cannot be represented by
+/// the underlying data structure.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{precise_sleep, Instant};
+///
+/// let d = Duration::from_micros(1500);
+/// let start = Instant::now();
+/// precise_sleep(d);
+/// assert!(start.elapsed() >= d);
+/// ```
+#[inline]
+pub fn precise_sleep(d: Duration) {
+    precise_sleep_with_threshold(d, DEFAULT_SPIN_THRESHOLD)
+}
+
+/// Puts the current thread to sleep for exactly `d`, as far as the clock
+/// permits.
+///
+/// The thread sleeps with `clock_nanosleep` until `spin_threshold` before the
+/// deadline, then busy-waits with [`spin_until`] for the remainder, so the
+/// wake-up isn't subject to timer slack and scheduling latency.
+///
+/// Spinning keeps a CPU core fully busy for up to `spin_threshold`: keep it as
+/// small as the required accuracy allows, a zero threshold disables spinning
+/// altogether.
+///
+/// # Panics
+///
+/// This function may panic if `Instant::now() + d` cannot be represented by
+/// the underlying data structure.
+pub fn precise_sleep_with_threshold(d: Duration, spin_threshold: Duration) {
+    let start = Instant::now();
+    let deadline = start + d;
+
+    if let Some(wake) = deadline.checked_sub(spin_threshold) {
+        if wake > start {
+            let wake = raw::Timespec::from(wake);
+            // If sleeping fails for any other reason the remaining time is
+            // just spun.
+            while let Err(Errno::EINTR) = wake.sleep_until(raw::INSTANT_CLOCKID) {}
+        }
+    }
+
+    spin_until(deadline);
+}
+
+/// Busy-waits until `deadline`, for waits too short to afford even a
+/// `clock_nanosleep(2)` round trip.
+///
+/// The thread doesn't yield: it keeps a CPU core fully busy, polling
+/// [`Instant::now`] with [`core::hint::spin_loop`] in between. Only use it for
+/// waits of a few microseconds at most, e.g. the tail of a
+/// [`precise_sleep`]; [`sleep_until`] is the right tool for anything longer.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::{spin_until, Instant};
+///
+/// let deadline = Instant::now() + Duration::from_micros(5);
+/// spin_until(deadline);
+/// assert!(Instant::now() >= deadline);
+/// ```
+#[inline]
+pub fn spin_until(deadline: Instant) {
+    while Instant::now() < deadline {
+        core::hint::spin_loop();
+    }
+}
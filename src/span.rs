@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+use core::{cell::RefCell, time::Duration};
+
+use crate::Instant;
+
+/// A lightweight profiler timing nested spans of code.
+///
+/// Spans are opened with [`SpanRecorder::enter`] and closed when their guard
+/// is dropped. The time spent in each span is accumulated in a tree keyed by
+/// span name: entering the same name twice under the same parent adds to a
+/// single node. The recorder is single-threaded, keep one per thread.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread::sleep, time::Duration};
+/// use unix_clock::SpanRecorder;
+///
+/// let recorder = SpanRecorder::new();
+/// {
+///     let _request = recorder.enter("request");
+///     for _ in 0..2 {
+///         let _query = recorder.enter("query");
+///         sleep(Duration::from_millis(5));
+///     }
+/// }
+///
+/// let report = recorder.report();
+/// let request = report.child("request").unwrap();
+/// let query = request.child("query").unwrap();
+/// assert_eq!((request.count, query.count), (1, 2));
+/// assert!(query.total >= Duration::from_millis(10));
+/// assert!(request.total >= query.total);
+/// ```
+#[derive(Debug, Default)]
+pub struct SpanRecorder {
+    inner: RefCell<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    // The root is node 0, every other node is a child of an earlier one.
+    nodes: Vec<Node>,
+    // Indices of the open spans, innermost last.
+    open: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct Node {
+    name: &'static str,
+    total: Duration,
+    count: u64,
+    children: Vec<usize>,
+}
+
+impl Node {
+    #[inline]
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            total: Duration::ZERO,
+            count: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl Default for Inner {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            nodes: alloc::vec![Node::new("")],
+            open: Vec::new(),
+        }
+    }
+}
+
+impl SpanRecorder {
+    /// Creates a recorder without any span.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a span named `name`, nested in the innermost span still open,
+    /// until the returned guard is dropped.
+    #[inline]
+    pub fn enter(&self, name: &'static str) -> SpanGuard<'_> {
+        let mut inner = self.inner.borrow_mut();
+        let parent = inner.open.last().copied().unwrap_or(0);
+        let node = match inner.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|&child| inner.nodes[child].name == name)
+        {
+            Some(node) => node,
+            None => {
+                inner.nodes.push(Node::new(name));
+                let node = inner.nodes.len() - 1;
+                inner.nodes[parent].children.push(node);
+                node
+            }
+        };
+        inner.open.push(node);
+        drop(inner);
+
+        SpanGuard {
+            recorder: self,
+            node,
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the tree of the spans closed so far.
+    ///
+    /// The root of the tree is unnamed, its total is the time accumulated by
+    /// the top-level spans.
+    pub fn report(&self) -> SpanTree {
+        let inner = self.inner.borrow();
+        let mut root = inner.tree(0);
+        root.total = root.children.iter().map(|child| child.total).sum();
+        root
+    }
+}
+
+impl Inner {
+    fn tree(&self, node: usize) -> SpanTree {
+        let node = &self.nodes[node];
+        SpanTree {
+            name: node.name,
+            total: node.total,
+            count: node.count,
+            children: node
+                .children
+                .iter()
+                .map(|&child| self.tree(child))
+                .collect(),
+        }
+    }
+}
+
+/// Closes a span of a [`SpanRecorder`] when dropped.
+#[must_use = "the span is closed as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct SpanGuard<'a> {
+    recorder: &'a SpanRecorder,
+    node: usize,
+    start: Instant,
+}
+
+impl<'a> Drop for SpanGuard<'a> {
+    fn drop(&mut self) {
+        let elapsed = Instant::now().saturating_duration_since(self.start);
+        let mut inner = self.recorder.inner.borrow_mut();
+        let node = &mut inner.nodes[self.node];
+        node.total = node.total.saturating_add(elapsed);
+        node.count += 1;
+        // Guards dropped out of order also close the spans nested in theirs.
+        if let Some(depth) = inner.open.iter().rposition(|&open| open == self.node) {
+            inner.open.truncate(depth);
+        }
+    }
+}
+
+/// A node of the report of a [`SpanRecorder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanTree {
+    /// The name of the span, empty for the root.
+    pub name: &'static str,
+    /// The time accumulated by every closing of the span.
+    pub total: Duration,
+    /// The number of times the span was closed.
+    pub count: u64,
+    /// The spans nested in this one, in order of first entry.
+    pub children: Vec<SpanTree>,
+}
+
+impl SpanTree {
+    /// Returns the child span named `name`.
+    #[must_use]
+    #[inline]
+    pub fn child(&self, name: &str) -> Option<&SpanTree> {
+        self.children.iter().find(|child| child.name == name)
+    }
+}
@@ -0,0 +1,198 @@
+use core::{cell::Cell, time::Duration};
+
+use crate::Instant;
+
+/// Lets an action run at most once per interval.
+///
+/// The classic use is rate limiting a log line: the first call to
+/// [`should_run`](Throttle::should_run) returns `true`, then it returns
+/// `false` until `interval` has elapsed since the last `true`.
+///
+/// This type is single-threaded, see `AtomicThrottle` under the `std`
+/// feature for one that can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use unix_clock::Throttle;
+///
+/// let warn = Throttle::new(Duration::from_secs(5));
+/// assert!(warn.should_run());
+/// assert!(!warn.should_run());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    interval: Duration,
+    last: Cell<Option<Instant>>,
+}
+
+impl Throttle {
+    /// Creates a throttle letting an action run once per `interval`.
+    #[inline]
+    pub const fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Returns the interval between two runs.
+    #[must_use]
+    #[inline]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns `true` if at least the interval has elapsed since the last time
+    /// it returned `true`.
+    #[inline]
+    pub fn should_run(&self) -> bool {
+        self.should_run_at(Instant::now())
+    }
+
+    /// Like [`should_run`](Throttle::should_run), taking `now` as the current
+    /// time.
+    ///
+    /// An instant earlier than the last run never lets the action run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::{Instant, Throttle};
+    ///
+    /// let start = Instant::now();
+    /// let throttle = Throttle::new(Duration::from_secs(5));
+    /// assert!(throttle.should_run_at(start));
+    /// assert!(!throttle.should_run_at(start + Duration::new(4, 999_999_999)));
+    /// assert!(throttle.should_run_at(start + Duration::from_secs(5)));
+    /// assert!(!throttle.should_run_at(start));
+    /// assert!(!throttle.should_run_at(start + Duration::from_secs(9)));
+    /// assert!(throttle.should_run_at(start + Duration::from_secs(11)));
+    /// ```
+    pub fn should_run_at(&self, now: Instant) -> bool {
+        let run = match self.last.get() {
+            Some(last) => matches!(now.checked_duration_since(last), Some(e) if e >= self.interval),
+            None => true,
+        };
+        if run {
+            self.last.set(Some(now));
+        }
+        run
+    }
+}
+
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub use self::atomic::AtomicThrottle;
+
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+mod atomic {
+    use core::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    };
+
+    use crate::Instant;
+
+    const NEVER: u64 = u64::MAX;
+
+    /// A [`Throttle`](crate::Throttle) that can be shared between threads.
+    ///
+    /// When several threads race past the interval boundary only one of them
+    /// gets `true`. It can be created in a `static`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use unix_clock::AtomicThrottle;
+    ///
+    /// static WARN: AtomicThrottle = AtomicThrottle::new(Duration::from_secs(5));
+    ///
+    /// let runs = (0..4)
+    ///     .map(|_| std::thread::spawn(|| WARN.should_run()))
+    ///     .collect::<Vec<_>>()
+    ///     .into_iter()
+    ///     .map(|handle| handle.join().unwrap())
+    ///     .filter(|&ran| ran)
+    ///     .count();
+    /// assert_eq!(runs, 1);
+    /// ```
+    #[derive(Debug)]
+    pub struct AtomicThrottle {
+        interval: Duration,
+        last: AtomicU64,
+    }
+
+    impl AtomicThrottle {
+        /// Creates a throttle letting an action run once per `interval`.
+        #[inline]
+        pub const fn new(interval: Duration) -> Self {
+            Self {
+                interval,
+                last: AtomicU64::new(NEVER),
+            }
+        }
+
+        /// Returns the interval between two runs.
+        #[must_use]
+        #[inline]
+        pub const fn interval(&self) -> Duration {
+            self.interval
+        }
+
+        /// Returns `true` if at least the interval has elapsed since the last
+        /// time it returned `true`.
+        #[inline]
+        pub fn should_run(&self) -> bool {
+            self.should_run_at(Instant::now())
+        }
+
+        /// Like [`should_run`](AtomicThrottle::should_run), taking `now` as the
+        /// current time.
+        ///
+        /// An instant earlier than the last run never lets the action run.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::time::Duration;
+        /// use unix_clock::{AtomicThrottle, Instant};
+        ///
+        /// let start = Instant::now();
+        /// let throttle = AtomicThrottle::new(Duration::from_secs(5));
+        /// assert!(throttle.should_run_at(start));
+        /// assert!(!throttle.should_run_at(start + Duration::new(4, 999_999_999)));
+        /// assert!(throttle.should_run_at(start + Duration::from_secs(5)));
+        /// assert!(!throttle.should_run_at(start));
+        /// ```
+        pub fn should_run_at(&self, now: Instant) -> bool {
+            let now = to_nanos(now);
+            let interval = u64::try_from(self.interval.as_nanos()).unwrap_or(u64::MAX);
+            let mut last = self.last.load(Ordering::Relaxed);
+            loop {
+                if last != NEVER && !matches!(now.checked_sub(last), Some(e) if e >= interval) {
+                    return false;
+                }
+                match self.last.compare_exchange_weak(
+                    last,
+                    now,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return true,
+                    Err(current) => last = current,
+                }
+            }
+        }
+    }
+
+    // Same count as `raw::monotonic_now_ns`, far from wrapping in practice.
+    #[inline]
+    fn to_nanos(t: Instant) -> u64 {
+        (t.t.secs() as u64)
+            .wrapping_mul(1_000_000_000)
+            .wrapping_add(t.t.nsecs() as u64)
+    }
+}
@@ -0,0 +1,186 @@
+use core::time::Duration;
+
+use crate::Instant;
+
+/// Reports an operation taking longer than a threshold, calling a callback
+/// with the elapsed time once.
+///
+/// The elapsed time is only compared with the threshold when
+/// [`check`](WatchdogTimer::check) is called, e.g. at every iteration of an
+/// event loop. See `WatchdogThread` under the `std` feature for a watchdog
+/// checking on its own.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, thread::sleep, time::Duration};
+/// use unix_clock::WatchdogTimer;
+///
+/// let fired = Cell::new(0);
+/// let mut watchdog = WatchdogTimer::new(Duration::from_millis(10), |_| {
+///     fired.set(fired.get() + 1)
+/// });
+/// assert!(!watchdog.check());
+/// assert_eq!(fired.get(), 0);
+///
+/// sleep(Duration::from_millis(20));
+/// assert!(watchdog.check());
+/// assert!(watchdog.check());
+/// assert_eq!(fired.get(), 1);
+/// ```
+pub struct WatchdogTimer<F: FnOnce(Duration)> {
+    start: Instant,
+    threshold: Duration,
+    callback: Option<F>,
+}
+
+impl<F: FnOnce(Duration)> WatchdogTimer<F> {
+    /// Starts a watchdog calling `callback` once more than `threshold` has
+    /// elapsed.
+    #[inline]
+    pub fn new(threshold: Duration, callback: F) -> Self {
+        Self {
+            start: Instant::now(),
+            threshold,
+            callback: Some(callback),
+        }
+    }
+
+    /// Returns the time elapsed since the watchdog started.
+    #[must_use]
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns `true` if more than the threshold has elapsed, calling the
+    /// callback the first time it happens.
+    pub fn check(&mut self) -> bool {
+        let elapsed = self.elapsed();
+        if elapsed <= self.threshold {
+            return false;
+        }
+        if let Some(callback) = self.callback.take() {
+            callback(elapsed);
+        }
+        true
+    }
+
+    /// Returns `true` if the callback has been called.
+    #[must_use]
+    #[inline]
+    pub fn has_fired(&self) -> bool {
+        self.callback.is_none()
+    }
+}
+
+impl<F: FnOnce(Duration)> core::fmt::Debug for WatchdogTimer<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WatchdogTimer")
+            .field("start", &self.start)
+            .field("threshold", &self.threshold)
+            .field("fired", &self.has_fired())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::thread::WatchdogThread;
+
+#[cfg(feature = "std")]
+mod thread {
+    use core::time::Duration;
+    use std::{
+        sync::mpsc::{self, RecvTimeoutError, Sender},
+        thread::JoinHandle,
+    };
+
+    use crate::{recv_deadline, Instant};
+
+    /// A watchdog checking on a background thread, calling a callback with
+    /// the elapsed time once if it isn't dropped within a threshold.
+    ///
+    /// Dropping the watchdog stops the thread, waiting for the callback to
+    /// return if it is running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{
+    ///     sync::{
+    ///         atomic::{AtomicUsize, Ordering},
+    ///         Arc,
+    ///     },
+    ///     thread::sleep,
+    ///     time::Duration,
+    /// };
+    /// use unix_clock::WatchdogThread;
+    ///
+    /// let fired = Arc::new(AtomicUsize::new(0));
+    ///
+    /// // stuck for longer than the threshold
+    /// let counter = Arc::clone(&fired);
+    /// let watchdog = WatchdogThread::spawn(Duration::from_millis(10), move |_| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// sleep(Duration::from_millis(50));
+    /// drop(watchdog);
+    /// assert_eq!(fired.load(Ordering::Relaxed), 1);
+    ///
+    /// // done in time
+    /// let counter = Arc::clone(&fired);
+    /// let watchdog = WatchdogThread::spawn(Duration::from_secs(10), move |_| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// drop(watchdog);
+    /// assert_eq!(fired.load(Ordering::Relaxed), 1);
+    /// ```
+    #[derive(Debug)]
+    pub struct WatchdogThread {
+        stop: Option<Sender<()>>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl WatchdogThread {
+        /// Spawns a thread calling `callback` once more than `threshold` has
+        /// elapsed, unless the watchdog is dropped first.
+        pub fn spawn<F>(threshold: Duration, callback: F) -> Self
+        where
+            F: FnOnce(Duration) + Send + 'static,
+        {
+            let start = Instant::now();
+            let deadline = start.checked_add(threshold);
+            let (stop, stopped) = mpsc::channel();
+            let handle = std::thread::spawn(move || {
+                let timed_out = match deadline {
+                    Some(deadline) => matches!(
+                        recv_deadline(&stopped, deadline),
+                        Err(RecvTimeoutError::Timeout)
+                    ),
+                    // Never reached.
+                    None => {
+                        let _ = stopped.recv();
+                        false
+                    }
+                };
+                if timed_out {
+                    callback(start.elapsed());
+                }
+            });
+            Self {
+                stop: Some(stop),
+                handle: Some(handle),
+            }
+        }
+    }
+
+    impl Drop for WatchdogThread {
+        fn drop(&mut self) {
+            // Disconnecting the channel wakes the thread up.
+            self.stop.take();
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
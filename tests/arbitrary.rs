@@ -0,0 +1,39 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use unix_clock::{raw::Timespec, Instant, SystemTime};
+
+// Fixed patterns plus an xorshift stream, enough to hit both ends of the
+// ranges `int_in_range` maps the bytes to.
+fn inputs() -> Vec<Vec<u8>> {
+    let mut inputs = vec![vec![], vec![0; 64], vec![0xff; 64]];
+    let mut x = 0x2545_f491_4f6c_dd1du64;
+    for _ in 0..256 {
+        inputs.push(
+            (0..64)
+                .map(|_| {
+                    x ^= x << 13;
+                    x ^= x >> 7;
+                    x ^= x << 17;
+                    x as u8
+                })
+                .collect(),
+        );
+    }
+    inputs
+}
+
+#[test]
+fn generates_normalized_values() {
+    for input in inputs() {
+        let mut u = Unstructured::new(&input);
+        let t = Timespec::arbitrary(&mut u).unwrap();
+        assert!(t.nsecs() < 1_000_000_000);
+        assert_eq!(t.normalized_parts(), (t.secs(), t.nsecs()));
+
+        let t = Timespec::from(SystemTime::arbitrary(&mut u).unwrap());
+        assert!(t.nsecs() < 1_000_000_000);
+        let t = Timespec::from(Instant::arbitrary(&mut u).unwrap());
+        assert!(t.nsecs() < 1_000_000_000);
+    }
+}
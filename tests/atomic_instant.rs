@@ -0,0 +1,69 @@
+#![cfg(target_has_atomic = "64")]
+
+use std::{sync::atomic::Ordering, thread, time::Duration};
+
+use unix_clock::{raw::Timespec, AtomicInstant, Instant};
+
+fn at(secs: i64, nsecs: u32) -> Instant {
+    Instant::from_timespec(Timespec::new(secs, nsecs))
+}
+
+#[test]
+fn round_trips_instants() {
+    let atomic = AtomicInstant::now();
+    for instant in [
+        at(0, 0),
+        at(1, 999_999_999),
+        at(86_400 * 365 * 500, 1),
+        Instant::now(),
+    ] {
+        atomic.store(instant, Ordering::Relaxed);
+        assert_eq!(atomic.load(Ordering::Relaxed), instant);
+    }
+    assert_eq!(AtomicInstant::from(at(7, 5)).into_inner(), at(7, 5));
+}
+
+#[test]
+fn saturates_outside_the_u64_range() {
+    let atomic = AtomicInstant::new(at(-1, 0));
+    assert_eq!(atomic.load(Ordering::Relaxed), at(0, 0));
+
+    // u64::MAX nanoseconds
+    let max = at(18_446_744_073, 709_551_615);
+    atomic.store(max + Duration::from_secs(1), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), max);
+}
+
+#[test]
+fn store_now_records_the_current_instant() {
+    let atomic = AtomicInstant::new(at(0, 0));
+    let before = Instant::now();
+    let stored = atomic.store_now(Ordering::Relaxed);
+    assert!(stored >= before && stored <= Instant::now());
+    assert_eq!(atomic.load(Ordering::Relaxed), stored);
+}
+
+#[test]
+fn fetch_max_never_moves_backwards() {
+    let atomic = AtomicInstant::new(at(10, 0));
+    assert_eq!(atomic.fetch_max(at(5, 0), Ordering::Relaxed), at(10, 0));
+    assert_eq!(atomic.load(Ordering::Relaxed), at(10, 0));
+    assert_eq!(atomic.fetch_max(at(20, 0), Ordering::Relaxed), at(10, 0));
+    assert_eq!(atomic.load(Ordering::Relaxed), at(20, 0));
+}
+
+#[test]
+fn concurrent_updates_keep_the_latest() {
+    let atomic = AtomicInstant::new(at(0, 0));
+    thread::scope(|s| {
+        for i in 0..8 {
+            let atomic = &atomic;
+            s.spawn(move || {
+                for j in 0..1_000 {
+                    atomic.fetch_max(at(i * 1_000 + j, 0), Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(atomic.into_inner(), at(7_999, 0));
+}
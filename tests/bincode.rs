@@ -0,0 +1,46 @@
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use unix_clock::{raw::Timespec, Instant, SystemTime};
+
+#[test]
+fn encodes_as_nanoseconds() {
+    let t = SystemTime::new(1, 2);
+    assert_eq!(
+        bincode::serialize(&t).unwrap(),
+        1_000_000_002i128.to_le_bytes()
+    );
+}
+
+#[test]
+fn round_trips() {
+    for t in [
+        SystemTime::UNIX_EPOCH,
+        SystemTime::new(1_700_000_000, 123_456_789),
+        SystemTime::new(-1, 999_999_999),
+        SystemTime::new(-1_700_000_000, 1),
+        SystemTime::from(Timespec::MIN),
+        SystemTime::from(Timespec::MAX),
+    ] {
+        let bytes = bincode::serialize(&t).unwrap();
+        assert_eq!(bincode::deserialize::<SystemTime>(&bytes).unwrap(), t);
+    }
+
+    let t = Timespec::new(1_700_000_000, 123_456_789);
+    let bytes = bincode::serialize(&t).unwrap();
+    assert_eq!(bincode::deserialize::<Timespec>(&bytes).unwrap(), t);
+}
+
+#[test]
+fn round_trips_instants() {
+    let now = Instant::now();
+    let bytes = bincode::serialize(&now).unwrap();
+    assert_eq!(bincode::deserialize::<Instant>(&bytes).unwrap(), now);
+}
+
+#[test]
+fn rejects_out_of_range_nanoseconds() {
+    for nanos in [Timespec::MAX.to_nanos_i128() + 1, i128::MIN] {
+        let bytes = bincode::serialize(&nanos).unwrap();
+        assert!(bincode::deserialize::<SystemTime>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(target_os = "linux")]
+
+use std::{thread::sleep, time::Duration};
+
+use unix_clock::{
+    raw::{self, ClockId},
+    BootInstant, Instant,
+};
+
+#[test]
+fn boottime_instant_advances() {
+    let start = Instant::now_with_clock(ClockId::Boottime).unwrap();
+    sleep(Duration::from_millis(10));
+    let end = Instant::now_with_clock(ClockId::Boottime).unwrap();
+    assert!(end.duration_since(start) >= Duration::from_millis(10));
+}
+
+#[cfg(feature = "boottime-instant")]
+#[test]
+fn instant_reads_boottime() {
+    assert_eq!(raw::ACTIVE_INSTANT_CLOCK, ClockId::Boottime);
+    let before = Instant::now_with_clock(ClockId::Boottime).unwrap();
+    let now = Instant::now();
+    let after = Instant::now_with_clock(ClockId::Boottime).unwrap();
+    assert!(before <= now && now <= after);
+}
+
+#[cfg(not(feature = "boottime-instant"))]
+#[test]
+fn instant_reads_monotonic() {
+    assert_eq!(raw::ACTIVE_INSTANT_CLOCK, ClockId::Monotonic);
+}
+
+#[test]
+fn boot_instant_advances_with_boottime() {
+    let boot_start = Instant::now_with_clock(ClockId::Boottime).unwrap();
+    let start = BootInstant::now();
+    sleep(Duration::from_millis(10));
+    let elapsed = start.elapsed();
+    let boot_elapsed = Instant::now_with_clock(ClockId::Boottime)
+        .unwrap()
+        .duration_since(boot_start);
+    assert!(elapsed >= Duration::from_millis(10));
+    assert!(elapsed <= boot_elapsed);
+}
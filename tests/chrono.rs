@@ -0,0 +1,77 @@
+// Where `time_t` is 32 bits wide the conversion from chrono is a `TryFrom`,
+// covered by its documentation.
+#![cfg(all(
+    feature = "chrono",
+    not(any(
+        all(target_os = "freebsd", target_arch = "x86"),
+        all(
+            any(
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "watchos",
+                target_os = "tvos"
+            ),
+            target_pointer_width = "32"
+        )
+    ))
+))]
+
+use chrono::{DateTime, Utc};
+use unix_clock::{raw::Timespec, Overflow, SystemTime};
+
+fn round_trip(t: SystemTime) {
+    let dt = DateTime::<Utc>::try_from(t).unwrap();
+    assert_eq!(SystemTime::from(dt), t);
+}
+
+#[test]
+fn converts_around_the_epoch() {
+    let dt = DateTime::<Utc>::try_from(SystemTime::UNIX_EPOCH).unwrap();
+    assert_eq!((dt.timestamp(), dt.timestamp_subsec_nanos()), (0, 0));
+
+    let dt = DateTime::<Utc>::try_from(SystemTime::new(-1, 999_999_999)).unwrap();
+    assert_eq!(
+        (dt.timestamp(), dt.timestamp_subsec_nanos()),
+        (-1, 999_999_999)
+    );
+
+    for t in [
+        SystemTime::new(-1, 0),
+        SystemTime::new(-1, 999_999_999),
+        SystemTime::UNIX_EPOCH,
+        SystemTime::new(0, 1),
+        SystemTime::new(1, 0),
+    ] {
+        round_trip(t);
+    }
+}
+
+#[test]
+fn keeps_sub_second_precision() {
+    let dt = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+    let t = SystemTime::from(dt);
+    assert_eq!(t, SystemTime::new(1_700_000_000, 123_456_789));
+    round_trip(t);
+    round_trip(SystemTime::new(1_700_000_000, 1));
+}
+
+#[test]
+fn folds_leap_seconds_into_the_next_second() {
+    let leap = DateTime::from_timestamp(1_483_228_799, 1_500_000_000).unwrap();
+    assert_eq!(
+        SystemTime::from(leap),
+        SystemTime::new(1_483_228_800, 500_000_000)
+    );
+}
+
+#[test]
+fn rejects_times_out_of_chrono_range() {
+    assert_eq!(
+        DateTime::<Utc>::try_from(SystemTime::from(Timespec::MAX)),
+        Err(Overflow { at_max: true })
+    );
+    assert_eq!(
+        DateTime::<Utc>::try_from(SystemTime::from(Timespec::MIN)),
+        Err(Overflow { at_max: false })
+    );
+}
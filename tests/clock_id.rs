@@ -0,0 +1,146 @@
+use unix_clock::raw::{ClockId, ClockIdError};
+
+#[test]
+fn cpu_time_clocks_are_not_cached() {
+    assert!(!ClockId::ProcessCputimeId.is_cached());
+    assert!(!ClockId::ThreadCputimeId.is_cached());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn classifies_linux_clocks() {
+    for clock in [ClockId::RealtimeCoarse, ClockId::MonotonicCoarse] {
+        assert!(clock.is_cached(), "{:?}", clock);
+    }
+    for clock in [
+        ClockId::Realtime,
+        ClockId::Monotonic,
+        ClockId::MonotonicRaw,
+        ClockId::Boottime,
+        ClockId::RealtimeAlarm,
+        ClockId::BoottimeAlarm,
+        ClockId::InternationalAtomicTime,
+    ] {
+        assert!(!clock.is_cached(), "{:?}", clock);
+    }
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+#[test]
+fn classifies_apple_clocks() {
+    for clock in [ClockId::MonotonicRawApprox, ClockId::UptimeRawApprox] {
+        assert!(clock.is_cached(), "{:?}", clock);
+    }
+    for clock in [
+        ClockId::Realtime,
+        ClockId::Monotonic,
+        ClockId::MonotonicRaw,
+        ClockId::UptimeRaw,
+    ] {
+        assert!(!clock.is_cached(), "{:?}", clock);
+    }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+#[test]
+fn classifies_freebsd_clocks() {
+    for clock in [
+        ClockId::RealtimeFast,
+        ClockId::MonotonicFast,
+        ClockId::UptimeFast,
+        ClockId::Second,
+        ClockId::RealtimeCoarse,
+        ClockId::MonotonicCoarse,
+    ] {
+        assert!(clock.is_cached(), "{:?}", clock);
+    }
+    for clock in [
+        ClockId::Realtime,
+        ClockId::RealtimePrecise,
+        ClockId::Monotonic,
+        ClockId::MonotonicPrecise,
+        ClockId::Uptime,
+        ClockId::UptimePrecise,
+        ClockId::Virtual,
+        ClockId::Prof,
+    ] {
+        assert!(!clock.is_cached(), "{:?}", clock);
+    }
+}
+
+#[cfg(any(target_os = "netbsd", target_os = "openbsd"))]
+#[test]
+fn no_clock_is_cached() {
+    assert!(!ClockId::Realtime.is_cached());
+    assert!(!ClockId::Monotonic.is_cached());
+}
+
+#[test]
+fn round_trips_raw_ids() {
+    for clock in [
+        ClockId::Realtime,
+        ClockId::Monotonic,
+        ClockId::ProcessCputimeId,
+        ClockId::ThreadCputimeId,
+    ] {
+        assert_eq!(ClockId::try_from(i32::from(clock)), Ok(clock));
+        assert_eq!(ClockId::try_from(u32::from(clock)), Ok(clock));
+    }
+}
+
+#[test]
+fn rejects_unknown_ids() {
+    assert_eq!(ClockId::try_from(-1i32), Err(ClockIdError::Unknown));
+    assert_eq!(ClockId::try_from(i32::MAX), Err(ClockIdError::Unknown));
+    assert_eq!(ClockId::try_from(u32::MAX), Err(ClockIdError::Unknown));
+}
+
+#[test]
+fn parses_names_it_prints() {
+    for clock in [
+        ClockId::Realtime,
+        ClockId::Monotonic,
+        ClockId::ProcessCputimeId,
+        ClockId::ThreadCputimeId,
+    ] {
+        assert_eq!(clock.to_string(), clock.name());
+        assert_eq!(clock.name().parse(), Ok(clock));
+        assert_eq!(clock.name().to_lowercase().parse(), Ok(clock));
+        assert_eq!(format!("{:?}", clock).parse(), Ok(clock));
+        assert_eq!(format!("{:?}", clock).to_uppercase().parse(), Ok(clock));
+    }
+    assert_eq!(format!("{:>16}", ClockId::Realtime), "  CLOCK_REALTIME");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn parses_linux_names() {
+    assert_eq!("CLOCK_TAI".parse(), Ok(ClockId::InternationalAtomicTime));
+    assert_eq!(
+        "internationalatomictime".parse(),
+        Ok(ClockId::InternationalAtomicTime)
+    );
+    assert_eq!("clock_monotonic_raw".parse(), Ok(ClockId::MonotonicRaw));
+    // only the names of the platform
+    assert_eq!(
+        "CLOCK_UPTIME".parse::<ClockId>(),
+        Err(ClockIdError::Unknown)
+    );
+}
+
+#[test]
+fn rejects_unknown_names() {
+    for name in ["", "CLOCK_", "Monotonic_", "CLOCK_MONOTONICX", "Mono"] {
+        assert_eq!(
+            name.parse::<ClockId>(),
+            Err(ClockIdError::Unknown),
+            "{:?}",
+            name
+        );
+    }
+}
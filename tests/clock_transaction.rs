@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use unix_clock::{
+    raw::{ClockTransaction, Timespec},
+    Errno, SystemTime,
+};
+
+// Generous enough to absorb a preemption between two reads.
+const TOLERANCE: Duration = Duration::from_millis(100);
+
+fn skew(a: SystemTime, b: SystemTime) -> Duration {
+    match a.duration_since(b) {
+        Ok(d) => d,
+        Err(e) => e.duration(),
+    }
+}
+
+#[test]
+fn unchanged_transaction_leaves_the_clock_alone() {
+    let before = SystemTime::now();
+    let transaction = ClockTransaction::begin().unwrap();
+    let restore = SystemTime::from(transaction.restore_time().unwrap());
+    drop(transaction);
+    assert!(skew(SystemTime::now(), before) < TOLERANCE);
+    assert!(skew(restore, before) < TOLERANCE);
+}
+
+// Needs `CAP_SYS_TIME`, and briefly moves the clock of the whole machine.
+#[test]
+#[ignore]
+fn restores_the_clock_after_drop() {
+    let mut transaction = ClockTransaction::begin().unwrap();
+    let future = SystemTime::now() + Duration::from_secs(3_600);
+    match transaction.set(&Timespec::from(future)) {
+        Err(Errno::EPERM) => return,
+        res => res.unwrap(),
+    }
+    assert!(skew(SystemTime::now(), future) < TOLERANCE);
+
+    std::thread::sleep(Duration::from_millis(50));
+    let expected = SystemTime::from(transaction.restore_time().unwrap());
+    drop(transaction);
+    assert!(skew(SystemTime::now(), expected) < TOLERANCE);
+    assert!(skew(SystemTime::now(), future) > Duration::from_secs(3_000));
+}
+
+// The kernel refuses non-settable clocks before checking privileges.
+#[cfg(target_os = "linux")]
+#[test]
+fn setting_a_non_settable_clock_fails_with_einval() {
+    use unix_clock::raw::ClockId;
+
+    let now = Timespec::now(ClockId::Monotonic).unwrap();
+    for clock in [ClockId::Monotonic, ClockId::MonotonicRaw, ClockId::Boottime] {
+        assert_eq!(now.set_clock_id(clock), Err(Errno::EINVAL), "{:?}", clock);
+    }
+}
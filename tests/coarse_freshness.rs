@@ -0,0 +1,15 @@
+#![cfg(debug_assertions)]
+
+use std::time::Duration;
+
+use unix_clock::CoarseWallClock;
+
+#[test]
+#[should_panic(expected = "behind the precise clock")]
+fn overstale_readings_trip_the_debug_assertion() {
+    // No coarse reading is as fresh as a precise one read after it.
+    CoarseWallClock::set_max_staleness(Duration::ZERO);
+    for _ in 0..1_000 {
+        let _ = CoarseWallClock.now();
+    }
+}
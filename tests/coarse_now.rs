@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use unix_clock::{Instant, SystemTime};
+
+// far above any tick length, below a noticeable hang
+const SLACK: Duration = Duration::from_millis(100);
+
+#[test]
+fn coarse_instants_share_the_instant_timeline() {
+    for _ in 0..1_000 {
+        let before = Instant::now();
+        let coarse = Instant::now_coarse();
+        let after = Instant::now();
+        assert!(coarse <= after);
+        assert!(before.saturating_duration_since(coarse) < SLACK);
+    }
+}
+
+#[test]
+fn coarse_instants_never_go_backwards() {
+    let mut prev = Instant::now_coarse();
+    for _ in 0..10_000 {
+        let now = Instant::now_coarse();
+        assert!(now >= prev);
+        prev = now;
+    }
+}
+
+#[test]
+fn coarse_system_time_lags_by_less_than_a_tick() {
+    let coarse = SystemTime::now_coarse();
+    let lag = SystemTime::now().saturating_duration_since(coarse);
+    assert!(lag < SLACK, "{:?}", lag);
+}
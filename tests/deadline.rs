@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use unix_clock::{raw::Timespec, Deadline, Instant};
+
+fn at(ms: u64) -> Instant {
+    Instant::from_timespec(Timespec::new(1_000, 0)) + Duration::from_millis(ms)
+}
+
+#[test]
+fn remaining_counts_down_to_expiry() {
+    let deadline = Deadline::at(at(100));
+    assert_eq!(
+        deadline.remaining_at(at(0)),
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(
+        deadline.remaining_at(at(99)),
+        Some(Duration::from_millis(1))
+    );
+    assert!(!deadline.is_expired_at(at(99)));
+
+    // expired from the deadline itself on
+    for ms in [100, 101, 10_000] {
+        assert_eq!(deadline.remaining_at(at(ms)), None);
+        assert!(deadline.is_expired_at(at(ms)));
+    }
+}
+
+#[test]
+fn sleep_blocks_until_expiry() {
+    let start = Instant::now();
+    let deadline = Deadline::after(Duration::from_millis(20));
+    deadline.sleep().unwrap();
+    assert!(deadline.is_expired());
+    assert_eq!(deadline.remaining(), None);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+}
+
+#[test]
+fn sleeping_on_an_expired_deadline_returns_immediately() {
+    let deadline = Deadline::at(Instant::now());
+    let start = Instant::now();
+    deadline.sleep().unwrap();
+    assert!(start.elapsed() < Duration::from_millis(50));
+}
@@ -0,0 +1,56 @@
+#![cfg(target_os = "linux")]
+
+use std::{fs::File, os::unix::io::AsRawFd};
+
+use unix_clock::{
+    raw::{clock_adjtime, AsRawClockId, ClockId, DynamicClock, Timespec, Timex},
+    Errno,
+};
+
+#[test]
+fn encodes_like_fd_to_clockid() {
+    for fd in [0, 1, 3, 42, 1 << 20] {
+        let id = DynamicClock::from_fd(fd).as_raw();
+        assert_eq!(DynamicClock::from_fd(fd).as_raw_clock_id(), id);
+        assert!(id < 0);
+        assert_eq!(id & 7, 3);
+        // CLOCKID_TO_FD
+        assert_eq!(!(id >> 3), fd);
+    }
+}
+
+#[test]
+fn rejects_descriptors_of_other_files() {
+    let file = File::open("/proc/self/exe").unwrap();
+    let clock = DynamicClock::from_fd(file.as_raw_fd());
+    assert_eq!(Timespec::now(clock), Err(Errno::EINVAL));
+    assert_eq!(Timespec::resolution(clock), Err(Errno::EINVAL));
+    assert_eq!(Timespec::zero().set_clock_id(clock), Err(Errno::EINVAL));
+    assert_eq!(
+        clock_adjtime(clock, &mut Timex::default()),
+        Err(Errno::EINVAL)
+    );
+}
+
+#[test]
+fn static_clocks_read_through_the_same_calls() {
+    fn read<C: AsRawClockId>(clock: C) -> Timespec {
+        Timespec::now(clock).unwrap()
+    }
+
+    let a = read(ClockId::Monotonic);
+    let b = read(ClockId::Monotonic);
+    assert!(b >= a);
+    assert_eq!(ClockId::Monotonic.as_raw_clock_id(), 1);
+}
+
+// Needs a PTP hardware clock, e.g. on a NIC with hardware timestamping.
+#[test]
+#[ignore]
+fn reads_a_ptp_clock() {
+    let ptp = File::open("/dev/ptp0").unwrap();
+    let clock = DynamicClock::from_fd(ptp.as_raw_fd());
+    let a = Timespec::now(clock).unwrap();
+    let b = Timespec::now(clock).unwrap();
+    assert!(b >= a);
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use unix_clock::SystemTime;
+
+const TEN_SECS: Duration = Duration::from_secs(10);
+const MINUTE: Duration = Duration::from_secs(60);
+
+#[test]
+fn aligns_to_a_10s_grid_across_a_boundary() {
+    let before = SystemTime::new(1_700_000_009, 999_999_999);
+    let after = SystemTime::new(1_700_000_010, 0);
+    assert_eq!(before.floor_to(TEN_SECS), SystemTime::new(1_700_000_000, 0));
+    assert_eq!(before.ceil_to(TEN_SECS), after);
+    assert_eq!(after.floor_to(TEN_SECS), after);
+    assert_eq!(after.ceil_to(TEN_SECS), after);
+    assert_eq!(
+        SystemTime::new(1_700_000_010, 1).ceil_to(TEN_SECS),
+        SystemTime::new(1_700_000_020, 0)
+    );
+}
+
+#[test]
+fn aligns_to_a_60s_grid_across_a_boundary() {
+    let before = SystemTime::new(1_699_999_979, 500_000_000);
+    let boundary = SystemTime::new(1_699_999_980, 0);
+    assert_eq!(before.floor_to(MINUTE), SystemTime::new(1_699_999_920, 0));
+    assert_eq!(before.ceil_to(MINUTE), boundary);
+    assert_eq!(boundary.floor_to(MINUTE), boundary);
+    assert_eq!(SystemTime::new(1_700_000_000, 0).floor_to(MINUTE), boundary);
+}
+
+#[test]
+fn floors_negative_seconds_towards_the_past() {
+    assert_eq!(
+        SystemTime::new(-1, 999_999_999).floor_to(TEN_SECS),
+        SystemTime::new(-10, 0)
+    );
+    assert_eq!(
+        SystemTime::new(-1, 999_999_999).ceil_to(TEN_SECS),
+        SystemTime::UNIX_EPOCH
+    );
+    assert_eq!(
+        SystemTime::new(-60, 0).floor_to(MINUTE),
+        SystemTime::new(-60, 0)
+    );
+    assert_eq!(
+        SystemTime::new(-61, 0).floor_to(MINUTE),
+        SystemTime::new(-120, 0)
+    );
+}
+
+#[test]
+fn zero_grid_is_identity() {
+    let t = SystemTime::new(1_234, 567);
+    assert_eq!(t.floor_to(Duration::ZERO), t);
+    assert_eq!(t.ceil_to(Duration::ZERO), t);
+}
@@ -0,0 +1,31 @@
+use unix_clock::{raw::Timespec, Instant, SystemTime};
+
+#[test]
+fn formats_nanoseconds_since_the_epoch() {
+    assert_eq!(format!("{:x}", SystemTime::UNIX_EPOCH), "0");
+    assert_eq!(format!("{:x}", SystemTime::new(1, 0)), "3b9aca00");
+    assert_eq!(
+        format!("{:x}", SystemTime::new(1_700_000_000, 123_456_789)),
+        "17979cfe3d85cd15"
+    );
+    assert_eq!(format!("{:#x}", SystemTime::new(0, 255)), "0xff");
+    assert_eq!(format!("{:08x}", SystemTime::new(0, 255)), "000000ff");
+}
+
+#[test]
+fn formats_pre_epoch_times_in_twos_complement() {
+    assert_eq!(
+        format!("{:x}", SystemTime::new(-1, 999_999_999)),
+        format!("{:x}", -1i128)
+    );
+    assert_eq!(
+        format!("{:x}", SystemTime::new(-1, 0)),
+        "ffffffffffffffffffffffffc4653600"
+    );
+}
+
+#[test]
+fn formats_instants_since_the_clock_zero() {
+    let t = Instant::from_timespec(Timespec::new(1, 1));
+    assert_eq!(format!("{:x}", t), "3b9aca01");
+}
@@ -0,0 +1,193 @@
+#![cfg(all(target_os = "linux", target_pointer_width = "64", feature = "io-uring"))]
+
+use std::{
+    ptr,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use linux_syscalls::{syscall, Sysno};
+use unix_clock::raw::{
+    io_uring::{io_uring_sqe, prepare_abs_timeout},
+    ClockId, Timespec,
+};
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct SqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    flags: u32,
+    dropped: u32,
+    array: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct CqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    overflow: u32,
+    cqes: u32,
+    flags: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct Params {
+    sq_entries: u32,
+    cq_entries: u32,
+    flags: u32,
+    sq_thread_cpu: u32,
+    sq_thread_idle: u32,
+    features: u32,
+    wq_fd: u32,
+    resv: [u32; 3],
+    sq_off: SqringOffsets,
+    cq_off: CqringOffsets,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+struct Cqe {
+    user_data: u64,
+    res: i32,
+    flags: u32,
+}
+
+const IORING_OFF_SQ_RING: usize = 0;
+const IORING_OFF_CQ_RING: usize = 0x800_0000;
+const IORING_OFF_SQES: usize = 0x1000_0000;
+const IORING_ENTER_GETEVENTS: usize = 1;
+const PROT_READ_WRITE: usize = 0x1 | 0x2;
+const MAP_SHARED_POPULATE: usize = 0x1 | 0x8000;
+const ETIME: i32 = 62;
+
+struct Mapping(*mut u8, usize);
+
+impl Mapping {
+    fn new(fd: usize, len: usize, offset: usize) -> Self {
+        let addr = unsafe {
+            syscall!(
+                Sysno::mmap,
+                0,
+                len,
+                PROT_READ_WRITE,
+                MAP_SHARED_POPULATE,
+                fd,
+                offset
+            )
+        }
+        .unwrap();
+        Self(addr as *mut u8, len)
+    }
+
+    unsafe fn at<T>(&self, offset: u32) -> *mut T {
+        self.0.add(offset as usize).cast()
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        let _ = unsafe { syscall!(Sysno::munmap, self.0, self.1) };
+    }
+}
+
+// Submits a single entry to a fresh ring and waits for its completion,
+// returning `None` if the kernel doesn't provide `io_uring`, e.g. in a
+// sandbox or with `kernel.io_uring_disabled` set.
+fn submit_and_wait(sqe: impl FnOnce(&mut io_uring_sqe)) -> Option<Cqe> {
+    let mut params = Params::default();
+    let fd = unsafe { syscall!(Sysno::io_uring_setup, 1, &mut params as *mut Params) }.ok()?;
+
+    let sq = Mapping::new(
+        fd,
+        params.sq_off.array as usize + params.sq_entries as usize * 4,
+        IORING_OFF_SQ_RING,
+    );
+    let cq = Mapping::new(
+        fd,
+        params.cq_off.cqes as usize + params.cq_entries as usize * core::mem::size_of::<Cqe>(),
+        IORING_OFF_CQ_RING,
+    );
+    let sqes = Mapping::new(
+        fd,
+        params.sq_entries as usize * core::mem::size_of::<io_uring_sqe>(),
+        IORING_OFF_SQES,
+    );
+
+    let cqe = unsafe {
+        sqe(&mut *sqes.at::<io_uring_sqe>(0));
+        let tail = &*sq.at::<AtomicU32>(params.sq_off.tail);
+        let mask = *sq.at::<u32>(params.sq_off.ring_mask);
+        let index = tail.load(Ordering::Relaxed);
+        *sq.at::<u32>(params.sq_off.array)
+            .add((index & mask) as usize) = 0;
+        tail.store(index.wrapping_add(1), Ordering::Release);
+
+        syscall!(
+            Sysno::io_uring_enter,
+            fd,
+            1,
+            1,
+            IORING_ENTER_GETEVENTS,
+            ptr::null::<u8>(),
+            0
+        )
+        .unwrap();
+
+        let head = &*cq.at::<AtomicU32>(params.cq_off.head);
+        let tail = (*cq.at::<AtomicU32>(params.cq_off.tail)).load(Ordering::Acquire);
+        let mask = *cq.at::<u32>(params.cq_off.ring_mask);
+        let index = head.load(Ordering::Relaxed);
+        assert_ne!(index, tail, "no completion");
+        let cqe = cq
+            .at::<Cqe>(params.cq_off.cqes)
+            .add((index & mask) as usize)
+            .read();
+        head.store(index.wrapping_add(1), Ordering::Release);
+        cqe
+    };
+
+    drop((sq, cq, sqes));
+    let _ = unsafe { syscall!(Sysno::close, fd) };
+    Some(cqe)
+}
+
+#[test]
+fn abs_timeout_completes_at_the_deadline() {
+    let start = Timespec::now(ClockId::Monotonic).unwrap();
+    let deadline = start
+        .checked_add_duration(&Duration::from_millis(50))
+        .unwrap();
+
+    let cqe = submit_and_wait(|sqe| {
+        // SAFETY: `deadline` outlives the ring.
+        unsafe { prepare_abs_timeout(sqe, &deadline, ClockId::Monotonic) }.unwrap();
+        sqe.user_data = 42;
+    });
+    let cqe = match cqe {
+        Some(cqe) => cqe,
+        // io_uring is unavailable
+        None => return,
+    };
+    let end = Timespec::now(ClockId::Monotonic).unwrap();
+
+    assert_eq!(cqe.user_data, 42);
+    assert_eq!(cqe.res, -ETIME);
+    let late = end
+        .sub_timespec(&deadline)
+        .expect("completed before the deadline");
+    assert!(late < Duration::from_secs(1), "completed {:?} late", late);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "test-util")]
+
+use std::time::Duration;
+
+use unix_clock::{Clock, Instant, ManualClock, SystemClock, SystemTime};
+
+// Code under test, generic over its clock.
+struct Lease<C> {
+    clock: C,
+    expires: Instant,
+}
+
+impl<C: Clock> Lease<C> {
+    fn new(clock: C, ttl: Duration) -> Self {
+        let expires = clock.now_instant() + ttl;
+        Self { clock, expires }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.clock.now_instant() < self.expires
+    }
+}
+
+#[test]
+fn injected_clock_drives_the_code() {
+    let clock = ManualClock::starting_now();
+    let lease = Lease::new(&clock, Duration::from_secs(30));
+    assert!(lease.is_valid());
+    clock.advance(Duration::from_secs(29));
+    assert!(lease.is_valid());
+    clock.advance(Duration::from_secs(1));
+    assert!(!lease.is_valid());
+}
+
+#[test]
+fn advance_moves_both_readings() {
+    let start = Instant::from_timespec(unix_clock::raw::Timespec::new(100, 0));
+    let clock = ManualClock::new(start, SystemTime::new(1_700_000_000, 0));
+    clock.advance(Duration::from_millis(1_500));
+    assert_eq!(clock.now_instant(), start + Duration::from_millis(1_500));
+    assert_eq!(
+        clock.now_system(),
+        SystemTime::new(1_700_000_001, 500_000_000)
+    );
+}
+
+#[test]
+fn set_system_leaves_the_instant_alone() {
+    let clock = ManualClock::default();
+    let start = clock.now_instant();
+    clock.set_system(SystemTime::new(-10, 0));
+    assert_eq!(clock.now_system(), SystemTime::new(-10, 0));
+    assert_eq!(clock.now_instant(), start);
+}
+
+#[test]
+fn system_clock_reads_the_system() {
+    let before = Instant::now();
+    let now = SystemClock.now_instant();
+    assert!(now >= before && now <= Instant::now());
+    assert!(SystemClock.now_system() > SystemTime::UNIX_EPOCH);
+
+    let clock: &dyn Clock = &SystemClock;
+    assert!(clock.now_instant() >= now);
+}
@@ -0,0 +1,50 @@
+use unix_clock::raw::Timespec;
+
+#[test]
+fn round_trips_across_the_range() {
+    let secs = [
+        Timespec::MIN.secs(),
+        Timespec::MIN.secs() + 1,
+        -1_700_000_000,
+        -1,
+        0,
+        1,
+        1_700_000_000,
+        Timespec::MAX.secs() - 1,
+        Timespec::MAX.secs(),
+    ];
+    for &secs in &secs {
+        for &nsecs in &[0, 1, 500_000_000, 999_999_999] {
+            let t = Timespec::new(secs, nsecs);
+            let nanos = t.to_nanos_i128();
+            assert_eq!(nanos, secs as i128 * 1_000_000_000 + nsecs as i128);
+            assert_eq!(Timespec::from_nanos_i128(nanos), t);
+        }
+    }
+}
+
+#[test]
+fn negative_counts_borrow_from_the_seconds() {
+    assert_eq!(
+        Timespec::from_nanos_i128(-1),
+        Timespec::new(-1, 999_999_999)
+    );
+    assert_eq!(
+        Timespec::from_nanos_i128(-999_999_999),
+        Timespec::new(-1, 1)
+    );
+    assert_eq!(
+        Timespec::from_nanos_i128(-1_000_000_000),
+        Timespec::new(-1, 0)
+    );
+}
+
+#[test]
+fn saturates_out_of_range() {
+    let max = Timespec::MAX.to_nanos_i128();
+    let min = Timespec::MIN.to_nanos_i128();
+    assert_eq!(Timespec::from_nanos_i128(max + 1), Timespec::MAX);
+    assert_eq!(Timespec::from_nanos_i128(min - 1), Timespec::MIN);
+    assert_eq!(Timespec::from_nanos_i128(i128::MAX), Timespec::MAX);
+    assert_eq!(Timespec::from_nanos_i128(i128::MIN), Timespec::MIN);
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use unix_clock::SystemTime;
+
+// The coarse clocks lag the precise one by up to a tick, 10ms at 100Hz.
+const TICK: Duration = Duration::from_millis(10);
+
+fn floor_second(t: SystemTime) -> SystemTime {
+    let since_epoch = t.duration_since(SystemTime::UNIX_EPOCH).unwrap();
+    SystemTime::UNIX_EPOCH + Duration::from_secs(since_epoch.as_secs())
+}
+
+#[test]
+fn truncates_to_the_second() {
+    let before = floor_second(SystemTime::now() - TICK);
+    let second = SystemTime::now_second();
+    let after = SystemTime::now();
+    let since_epoch = second.duration_since(SystemTime::UNIX_EPOCH).unwrap();
+    assert_eq!(since_epoch.subsec_nanos(), 0);
+    assert!(before <= second && second <= after);
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+#[test]
+fn reads_clock_second() {
+    use unix_clock::raw::{ClockId, Timespec};
+
+    let before = Timespec::now(ClockId::Second).unwrap();
+    let second = SystemTime::now_second();
+    let after = Timespec::now(ClockId::Second).unwrap();
+    assert!(SystemTime::from(Timespec::new(before.secs(), 0)) <= second);
+    assert!(second <= SystemTime::from(Timespec::new(after.secs(), 0)));
+}
@@ -0,0 +1,98 @@
+use unix_clock::raw::{Timespec, TIMESPEC_SIZE};
+
+#[test]
+fn built_timespecs_have_zeroed_padding() {
+    for t in [
+        Timespec::zero(),
+        Timespec::new(-1, 999_999_999),
+        Timespec::MAX,
+        Timespec::MIN,
+    ] {
+        assert!(t.validate_padding(), "{:?}", t);
+    }
+}
+
+#[test]
+fn read_timespecs_have_zeroed_padding() {
+    let now = Timespec::now(unix_clock::raw::ClockId::Monotonic).unwrap();
+    assert!(now.validate_padding());
+}
+
+#[test]
+fn accepts_zeroed_padding() {
+    assert_eq!(
+        Timespec::from_bytes(&[0; TIMESPEC_SIZE], true),
+        Some(Timespec::zero())
+    );
+}
+
+#[cfg(all(target_os = "linux", target_endian = "little"))]
+#[test]
+fn rejects_corrupted_padding() {
+    // seconds, nanoseconds, then the padding
+    let mut bytes = [0; TIMESPEC_SIZE];
+    bytes[..8].copy_from_slice(&7i64.to_le_bytes());
+    bytes[8..12].copy_from_slice(&5u32.to_le_bytes());
+    assert_eq!(
+        Timespec::from_bytes(&bytes, true),
+        Some(Timespec::new(7, 5))
+    );
+
+    bytes[12] = 1;
+    let t = Timespec::from_bytes(&bytes, false).unwrap();
+    assert!(!t.validate_padding());
+    assert_eq!((t.secs(), t.nsecs()), (7, 5));
+    assert_eq!(Timespec::from_bytes(&bytes, true), None);
+}
+
+// What the kernel writes, on 32-bit targets too: a `__kernel_timespec` of two
+// native `i64`s.
+#[cfg(target_os = "linux")]
+fn kernel_timespec(sec: i64, nsec: i64) -> [u8; TIMESPEC_SIZE] {
+    let mut bytes = [0; TIMESPEC_SIZE];
+    bytes[..8].copy_from_slice(&sec.to_ne_bytes());
+    bytes[8..].copy_from_slice(&nsec.to_ne_bytes());
+    bytes
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn reads_kernel_timespecs_near_second_boundaries() {
+    for (sec, nsec) in [
+        (0, 0),
+        (0, 999_999_999),
+        (1, 0),
+        (-1, 999_999_999),
+        (1_700_000_000, 999_999_999),
+        (i64::MAX, 999_999_999),
+        (i64::MIN, 0),
+    ] {
+        let t = Timespec::from_bytes(&kernel_timespec(sec, nsec), true).unwrap();
+        assert_eq!((t.secs(), t.nsecs()), (sec, nsec as u32));
+        let written = Timespec::new(sec, nsec as u32);
+        let written: [u8; TIMESPEC_SIZE] = unsafe { std::mem::transmute(written) };
+        assert_eq!(written, kernel_timespec(sec, nsec));
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn rejects_kernel_timespecs_with_oversized_nanoseconds() {
+    let bytes = kernel_timespec(0, 1 << 32);
+    assert_eq!(Timespec::from_bytes(&bytes, true), None);
+}
+
+#[test]
+fn clock_reads_stay_normalized_across_second_boundaries() {
+    use unix_clock::raw::ClockId;
+
+    // read until the seconds tick over
+    let first = Timespec::now(ClockId::Realtime).unwrap();
+    let mut last = first;
+    while last.secs() == first.secs() {
+        let t = Timespec::now(ClockId::Realtime).unwrap();
+        assert!(t.validate_padding(), "{:?}", t);
+        assert!(t.nsecs() < 1_000_000_000, "{:?}", t);
+        last = t;
+    }
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use unix_clock::{raw::Timespec, Instant, PhaseLockedClock};
+
+fn start() -> Instant {
+    Instant::from_timespec(Timespec::new(1_000, 0))
+}
+
+#[test]
+fn first_observation_steps_onto_the_reference() {
+    let mut clock = PhaseLockedClock::new_at(start());
+    let reference = start() + Duration::from_secs(5);
+    clock.observe_at(start(), reference);
+    assert_eq!(clock.adjusted_at(start()), reference);
+}
+
+#[test]
+fn negative_error_never_goes_backwards() {
+    let mut clock = PhaseLockedClock::new_at(start());
+    clock.observe_at(start(), start());
+
+    // the reference lags 10ms behind at every observation
+    let mut last = clock.adjusted_at(start());
+    for i in 1..=200u64 {
+        let local = start() + Duration::from_millis(100 * i);
+        let reference = local - Duration::from_millis(10);
+        for step in 0..=100 {
+            let t = local - Duration::from_millis(100) + Duration::from_millis(step);
+            let adjusted = clock.adjusted_at(t);
+            assert!(adjusted >= last, "went backwards at {i}/{step}");
+            last = adjusted;
+        }
+        let before = clock.adjusted_at(local);
+        clock.observe_at(local, reference);
+        assert!(clock.adjusted_at(local) >= before);
+    }
+}
+
+#[test]
+fn rate_is_bounded() {
+    let mut clock = PhaseLockedClock::new_at(start());
+    clock.observe_at(start(), start());
+    let local = start() + Duration::from_secs(1);
+    clock.observe_at(local, local + Duration::from_secs(10));
+    let next = local + Duration::from_secs(1);
+    let elapsed = clock.adjusted_at(next) - clock.adjusted_at(local);
+    assert!(elapsed <= Duration::from_micros(1_000_500));
+    assert!(elapsed >= Duration::from_micros(999_500));
+}
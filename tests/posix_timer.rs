@@ -0,0 +1,57 @@
+#![cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos",
+    target_os = "openbsd"
+)))]
+
+use std::{
+    os::raw::c_int,
+    sync::atomic::{AtomicBool, Ordering},
+    thread::sleep,
+    time::Duration,
+};
+
+use unix_clock::{
+    raw::{ClockId, Itimerspec, PosixTimer, SigevNotify, Timespec},
+    Instant,
+};
+
+// The same on every supported platform.
+const SIGALRM: c_int = 14;
+
+extern "C" {
+    // From the libc std links against, which takes care of the restorer
+    // trampoline `rt_sigaction(2)` needs.
+    fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+}
+
+static FIRED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn on_alarm(_: c_int) {
+    FIRED.store(true, Ordering::SeqCst);
+}
+
+#[test]
+fn signals_its_expiration() {
+    unsafe { signal(SIGALRM, on_alarm) };
+
+    let timer = PosixTimer::new(ClockId::Monotonic, SigevNotify::Signal(SIGALRM)).unwrap();
+    timer
+        .set_time(
+            &Itimerspec {
+                interval: Timespec::zero(),
+                value: Timespec::from_duration(Duration::from_millis(10)),
+            },
+            false,
+        )
+        .unwrap();
+
+    let give_up = Instant::now() + Duration::from_secs(5);
+    while !FIRED.load(Ordering::SeqCst) {
+        assert!(Instant::now() < give_up, "the timer never signalled");
+        sleep(Duration::from_millis(1));
+    }
+    assert_eq!(timer.get_time().unwrap(), Itimerspec::default());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+use unix_clock::{raw::Timespec, Instant, SystemTime};
+
+#[test]
+fn encodes_as_nanoseconds() {
+    let mut buf = [0; 16];
+    let t = SystemTime::new(1, 2);
+    let nanos = postcard::to_slice(&1_000_000_002i128, &mut [0; 16])
+        .unwrap()
+        .to_vec();
+    assert_eq!(postcard::to_slice(&t, &mut buf).unwrap(), &nanos[..]);
+
+    // a single encoding across the epoch: zigzag varint of -1
+    let t = SystemTime::new(-1, 999_999_999);
+    assert_eq!(postcard::to_slice(&t, &mut buf).unwrap(), [1]);
+}
+
+#[test]
+fn round_trips_through_a_stack_buffer() {
+    let mut buf = [0; 16];
+    for t in [
+        SystemTime::UNIX_EPOCH,
+        SystemTime::new(1_700_000_000, 123_456_789),
+        SystemTime::new(-1, 999_999_999),
+        SystemTime::new(-1_700_000_000, 1),
+        SystemTime::from(Timespec::MIN),
+        SystemTime::from(Timespec::MAX),
+    ] {
+        let bytes = postcard::to_slice(&t, &mut buf).unwrap();
+        assert!(bytes.len() <= 14);
+        assert_eq!(postcard::from_bytes::<SystemTime>(bytes).unwrap(), t);
+    }
+}
+
+#[test]
+fn round_trips_instants() {
+    let mut buf = [0; 16];
+    let now = Instant::now();
+    let bytes = postcard::to_slice(&now, &mut buf).unwrap();
+    assert_eq!(postcard::from_bytes::<Instant>(bytes).unwrap(), now);
+}
+
+#[test]
+fn rejects_out_of_range_nanoseconds() {
+    let mut buf = [0; 32];
+    for nanos in [
+        Timespec::MAX.to_nanos_i128() + 1,
+        Timespec::MIN.to_nanos_i128() - 1,
+        i128::MAX,
+        i128::MIN,
+    ] {
+        let bytes = postcard::to_slice(&nanos, &mut buf).unwrap();
+        assert!(postcard::from_bytes::<SystemTime>(bytes).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "watchos",
+    target_os = "tvos"
+))]
+
+use std::{thread::sleep, time::Duration};
+
+use unix_clock::Instant;
+
+#[test]
+fn raw_instants_never_go_backwards() {
+    let mut prev = Instant::now_raw();
+    for _ in 0..10_000 {
+        let now = Instant::try_now_raw().unwrap();
+        assert!(now >= prev);
+        prev = now;
+    }
+}
+
+#[test]
+fn raw_clock_ticks_at_the_pace_of_instant() {
+    let (start, raw_start) = (Instant::now(), Instant::now_raw());
+    sleep(Duration::from_millis(100));
+    let (raw_elapsed, elapsed) = (Instant::now_raw() - raw_start, start.elapsed());
+
+    // slewing is bounded by 500ppm, far below the scheduling noise
+    let diff = if raw_elapsed > elapsed {
+        raw_elapsed - elapsed
+    } else {
+        elapsed - raw_elapsed
+    };
+    assert!(
+        diff < Duration::from_millis(5),
+        "{:?} vs {:?}",
+        raw_elapsed,
+        elapsed
+    );
+}
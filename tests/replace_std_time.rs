@@ -0,0 +1,24 @@
+use std::thread::sleep;
+
+unix_clock::replace_std_time!(clock);
+
+use clock::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn shimmed_instant_measures_plausible_timings() {
+    let start = Instant::now();
+    sleep(Duration::from_millis(20));
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(20));
+    assert!(elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn shimmed_system_time_agrees_with_std() {
+    let ours = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let std = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+    let skew = if std > ours { std - ours } else { ours - std };
+    assert!(skew < Duration::from_millis(50));
+}
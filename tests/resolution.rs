@@ -0,0 +1,18 @@
+#![cfg(any(target_os = "linux", target_os = "android"))]
+
+use unix_clock::raw::{self, ClockId, Timespec};
+
+// Disabling the vdso is process-wide, hence a test binary of its own.
+#[test]
+fn vdso_and_syscall_agree() {
+    let clocks = [
+        ClockId::Realtime,
+        ClockId::Monotonic,
+        ClockId::MonotonicCoarse,
+        ClockId::Boottime,
+    ];
+    let vdso = clocks.map(|clock| Timespec::resolution(clock).unwrap());
+    raw::disable_vdso();
+    let syscall = clocks.map(|clock| Timespec::resolution(clock).unwrap());
+    assert_eq!(vdso, syscall);
+}
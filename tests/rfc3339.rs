@@ -0,0 +1,51 @@
+#![cfg(feature = "rfc3339")]
+
+use unix_clock::{raw::Timespec, SystemTime};
+
+fn fmt(secs: i64, nanos: u32) -> String {
+    SystemTime::new(secs, nanos).to_string()
+}
+
+#[test]
+fn formats_sub_second_precision() {
+    assert_eq!(fmt(1_714_566_896, 0), "2024-05-01T12:34:56Z");
+    assert_eq!(fmt(1_714_566_896, 789_000_000), "2024-05-01T12:34:56.789Z");
+    assert_eq!(
+        fmt(1_714_566_896, 789_100_000),
+        "2024-05-01T12:34:56.789100Z"
+    );
+    assert_eq!(fmt(1_714_566_896, 1), "2024-05-01T12:34:56.000000001Z");
+}
+
+#[test]
+fn formats_calendar_edges() {
+    assert_eq!(fmt(951_782_400, 0), "2000-02-29T00:00:00Z");
+    assert_eq!(fmt(1_735_689_599, 0), "2024-12-31T23:59:59Z");
+    assert_eq!(fmt(253_402_300_799, 0), "9999-12-31T23:59:59Z");
+    assert_eq!(fmt(253_402_300_800, 0), "+10000-01-01T00:00:00Z");
+}
+
+#[test]
+fn formats_pre_epoch_times() {
+    assert_eq!(fmt(-1, 0), "1969-12-31T23:59:59Z");
+    assert_eq!(fmt(-1, 500_000_000), "1969-12-31T23:59:59.500Z");
+    assert_eq!(fmt(-86_400, 0), "1969-12-31T00:00:00Z");
+    assert_eq!(fmt(-62_167_219_200, 0), "0000-01-01T00:00:00Z");
+    assert_eq!(fmt(-62_167_219_201, 0), "-0001-12-31T23:59:59Z");
+}
+
+#[test]
+fn formats_extremes() {
+    for t in [Timespec::MIN, Timespec::MAX] {
+        let s = SystemTime::from(t).to_string();
+        assert!(s.ends_with('Z'), "{}", s);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_rfc3339_matches_display() {
+    let t = SystemTime::new(1_700_000_000, 123_456_789);
+    assert_eq!(t.to_rfc3339(), t.to_string());
+    assert_eq!(t.to_rfc3339(), "2023-11-14T22:13:20.123456789Z");
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use unix_clock::{raw::Timespec, Instant, ScaledClock};
+
+fn start() -> Instant {
+    Instant::from_timespec(Timespec::new(1_000, 0))
+}
+
+#[test]
+fn double_speed() {
+    let clock = ScaledClock::new_at(start(), 2, 1);
+    for ms in [0, 1, 250, 1_000, 60_000] {
+        let real = start() + Duration::from_millis(ms);
+        assert_eq!(clock.now_at(real) - start(), Duration::from_millis(2 * ms));
+    }
+}
+
+#[test]
+fn half_speed() {
+    let clock = ScaledClock::new_at(start(), 1, 2);
+    for ms in [0, 2, 500, 1_000, 60_000] {
+        let real = start() + Duration::from_millis(ms);
+        assert_eq!(clock.now_at(real) - start(), Duration::from_millis(ms / 2));
+    }
+    // odd nanoseconds are truncated
+    let real = start() + Duration::from_nanos(3);
+    assert_eq!(clock.now_at(real) - start(), Duration::from_nanos(1));
+}
+
+#[test]
+fn rational_scale_does_not_drift() {
+    let clock = ScaledClock::new_at(start(), 1, 3);
+    let real = start() + Duration::from_secs(3 * 86_400 * 365);
+    assert_eq!(
+        clock.now_at(real) - start(),
+        Duration::from_secs(86_400 * 365)
+    );
+}
+
+#[test]
+fn set_scale_keeps_virtual_time_continuous() {
+    let mut clock = ScaledClock::new_at(start(), 2, 1);
+    let switch = start() + Duration::from_secs(10);
+    let before = clock.now_at(switch);
+    clock.set_scale_at(switch, 1, 2);
+    assert_eq!(clock.scale(), (1, 2));
+    assert_eq!(clock.now_at(switch), before);
+    assert_eq!(
+        clock.now_at(switch + Duration::from_secs(10)),
+        before + Duration::from_secs(5)
+    );
+
+    // a zero scale freezes the clock
+    let pause = switch + Duration::from_secs(20);
+    clock.set_scale_at(pause, 0, 1);
+    let frozen = clock.now_at(pause);
+    assert_eq!(clock.now_at(pause + Duration::from_secs(100)), frozen);
+}
+
+#[test]
+fn saturates_at_the_bounds() {
+    let clock = ScaledClock::new_at(start(), u32::MAX, 1);
+    let max = Instant::from_timespec(Timespec::MAX);
+    assert_eq!(clock.now_at(max), max);
+}
+
+#[test]
+#[should_panic = "zero denominator"]
+fn zero_denominator_panics() {
+    let _ = ScaledClock::new_at(start(), 1, 0);
+}
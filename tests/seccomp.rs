@@ -0,0 +1,102 @@
+#![cfg(all(target_os = "linux", target_pointer_width = "64"))]
+
+use linux_syscalls::{syscall, Sysno};
+
+#[repr(C)]
+struct SockFilter {
+    code: u16,
+    jt: u8,
+    jf: u8,
+    k: u32,
+}
+
+#[repr(C)]
+struct SockFprog {
+    len: u16,
+    filter: *const SockFilter,
+}
+
+const BPF_LD_W_ABS: u16 = 0x20;
+const BPF_JMP_JEQ_K: u16 = 0x15;
+const BPF_RET_K: u16 = 0x06;
+const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+const EPERM: u32 = 1;
+const PR_SET_NO_NEW_PRIVS: usize = 38;
+const PR_SET_SECCOMP: usize = 22;
+const SECCOMP_MODE_FILTER: usize = 2;
+
+// Makes `clock_gettime` fail with `EPERM` on the calling thread only.
+fn deny_clock_gettime() -> bool {
+    let filter = [
+        // load seccomp_data.nr
+        SockFilter {
+            code: BPF_LD_W_ABS,
+            jt: 0,
+            jf: 0,
+            k: 0,
+        },
+        SockFilter {
+            code: BPF_JMP_JEQ_K,
+            jt: 0,
+            jf: 1,
+            k: Sysno::clock_gettime as u32,
+        },
+        SockFilter {
+            code: BPF_RET_K,
+            jt: 0,
+            jf: 0,
+            k: SECCOMP_RET_ERRNO | EPERM,
+        },
+        SockFilter {
+            code: BPF_RET_K,
+            jt: 0,
+            jf: 0,
+            k: SECCOMP_RET_ALLOW,
+        },
+    ];
+    let prog = SockFprog {
+        len: filter.len() as u16,
+        filter: filter.as_ptr(),
+    };
+    unsafe {
+        syscall!(Sysno::prctl, PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0).is_ok()
+            && syscall!(
+                Sysno::prctl,
+                PR_SET_SECCOMP,
+                SECCOMP_MODE_FILTER,
+                &prog as *const SockFprog
+            )
+            .is_ok()
+    }
+}
+
+#[test]
+fn now_panics_naming_the_clock_and_errno() {
+    // A vdso read never enters the kernel, so seccomp can't deny it.
+    unix_clock::raw::disable_vdso();
+
+    let res = std::thread::spawn(|| {
+        if !deny_clock_gettime() {
+            // seccomp is unavailable, e.g. in a sandbox
+            return None;
+        }
+        Some(std::panic::catch_unwind(unix_clock::Instant::now))
+    })
+    .join()
+    .unwrap();
+
+    if let Some(res) = res {
+        let payload = res.unwrap_err();
+        let msg = payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .unwrap_or_default();
+        let clock = if cfg!(feature = "boottime-instant") {
+            "CLOCK_BOOTTIME"
+        } else {
+            "CLOCK_MONOTONIC"
+        };
+        assert_eq!(msg, format!("clock_gettime({}) failed: EPERM", clock));
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use unix_clock::{raw::Timespec, Instant, SystemTime};
+
+#[test]
+fn encodes_as_secs_nsecs_struct() {
+    let t = SystemTime::new(1_700_000_000, 123_456_789);
+    let json = serde_json::to_string(&t).unwrap();
+    assert_eq!(json, r#"{"secs":1700000000,"nsecs":123456789}"#);
+    assert_eq!(serde_json::from_str::<SystemTime>(&json).unwrap(), t);
+
+    let ts = Timespec::new(-1, 999_999_999);
+    let json = serde_json::to_string(&ts).unwrap();
+    assert_eq!(json, r#"{"secs":-1,"nsecs":999999999}"#);
+    assert_eq!(serde_json::from_str::<Timespec>(&json).unwrap(), ts);
+}
+
+#[test]
+fn round_trips_instants() {
+    let now = Instant::now();
+    let json = serde_json::to_string(&now).unwrap();
+    assert_eq!(serde_json::from_str::<Instant>(&json).unwrap(), now);
+}
+
+#[test]
+fn accepts_fields_in_any_order_and_sequences() {
+    let t = SystemTime::new(1, 2);
+    assert_eq!(
+        serde_json::from_str::<SystemTime>(r#"{"nsecs":2,"secs":1}"#).unwrap(),
+        t
+    );
+    assert_eq!(serde_json::from_str::<SystemTime>("[1,2]").unwrap(), t);
+}
+
+#[test]
+fn rejects_invalid_input() {
+    for json in [
+        r#"{"secs":1,"nsecs":1000000000}"#,
+        r#"{"secs":1}"#,
+        r#"{"secs":1,"secs":1,"nsecs":0}"#,
+        r#"{"secs":1,"nsecs":0,"nanos":0}"#,
+        "[1]",
+    ] {
+        assert!(
+            serde_json::from_str::<SystemTime>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use unix_clock::{serde_std_compat, SystemTime};
+
+struct Compat(SystemTime);
+
+impl Serialize for Compat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_std_compat::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Compat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_std_compat::deserialize(deserializer).map(Compat)
+    }
+}
+
+const TIMES: [(u64, u32); 3] = [(0, 0), (1_700_000_000, 123_456_789), (1, 999_999_999)];
+
+#[test]
+fn reads_back_as_std() {
+    let mut buf = [0; 32];
+    for &(secs, nanos) in &TIMES {
+        let t = SystemTime::UNIX_EPOCH + Duration::new(secs, nanos);
+        let bytes = postcard::to_slice(&Compat(t), &mut buf).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<std::time::SystemTime>(bytes).unwrap(),
+            std::time::SystemTime::UNIX_EPOCH + Duration::new(secs, nanos)
+        );
+    }
+}
+
+#[test]
+fn reads_std_back() {
+    let mut buf = [0; 32];
+    for &(secs, nanos) in &TIMES {
+        let t = std::time::SystemTime::UNIX_EPOCH + Duration::new(secs, nanos);
+        let bytes = postcard::to_slice(&t, &mut buf).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<Compat>(bytes).unwrap().0,
+            SystemTime::UNIX_EPOCH + Duration::new(secs, nanos)
+        );
+    }
+}
+
+#[test]
+fn rejects_pre_epoch_times() {
+    let mut buf = [0; 32];
+    assert!(postcard::to_slice(&Compat(SystemTime::new(-1, 0)), &mut buf).is_err());
+}
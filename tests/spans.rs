@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use std::{thread::sleep, time::Duration};
+
+use unix_clock::SpanRecorder;
+
+#[test]
+fn builds_a_two_level_tree() {
+    let recorder = SpanRecorder::new();
+    for _ in 0..3 {
+        let _outer = recorder.enter("outer");
+        {
+            let _parse = recorder.enter("parse");
+            sleep(Duration::from_millis(2));
+        }
+        let _eval = recorder.enter("eval");
+        sleep(Duration::from_millis(1));
+    }
+    {
+        let _other = recorder.enter("other");
+    }
+
+    let report = recorder.report();
+    assert_eq!(report.name, "");
+    let names: Vec<_> = report.children.iter().map(|child| child.name).collect();
+    assert_eq!(names, ["outer", "other"]);
+
+    let outer = report.child("outer").unwrap();
+    assert_eq!(outer.count, 3);
+    let names: Vec<_> = outer.children.iter().map(|child| child.name).collect();
+    assert_eq!(names, ["parse", "eval"]);
+
+    let parse = outer.child("parse").unwrap();
+    let eval = outer.child("eval").unwrap();
+    assert_eq!((parse.count, eval.count), (3, 3));
+    assert!(parse.children.is_empty() && eval.children.is_empty());
+    assert!(parse.total >= Duration::from_millis(6));
+    assert!(eval.total >= Duration::from_millis(3));
+    assert!(outer.total >= parse.total + eval.total);
+
+    let other = report.child("other").unwrap();
+    assert_eq!(report.total, outer.total + other.total);
+}
+
+#[test]
+fn same_name_under_different_parents_is_kept_apart() {
+    let recorder = SpanRecorder::new();
+    {
+        let _a = recorder.enter("a");
+        let _io = recorder.enter("io");
+    }
+    {
+        let _b = recorder.enter("b");
+        let _io = recorder.enter("io");
+    }
+    {
+        let _io = recorder.enter("io");
+    }
+
+    let report = recorder.report();
+    assert_eq!(report.children.len(), 3);
+    assert_eq!(report.child("a").unwrap().child("io").unwrap().count, 1);
+    assert_eq!(report.child("b").unwrap().child("io").unwrap().count, 1);
+    assert_eq!(report.child("io").unwrap().count, 1);
+}
+
+#[test]
+fn open_spans_are_not_reported() {
+    let recorder = SpanRecorder::new();
+    let _open = recorder.enter("open");
+    let report = recorder.report();
+    let open = report.child("open").unwrap();
+    assert_eq!((open.count, open.total), (0, Duration::ZERO));
+}
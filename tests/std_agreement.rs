@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+// Generous enough to absorb a preemption between the two reads.
+const TOLERANCE: Duration = Duration::from_millis(50);
+
+#[test]
+fn system_time_agrees_with_std() {
+    for _ in 0..100 {
+        let ours = unix_clock::SystemTime::now();
+        let std = std::time::SystemTime::now();
+
+        let ours = std::time::SystemTime::from(ours);
+        let skew = match std.duration_since(ours) {
+            Ok(d) => d,
+            Err(e) => e.duration(),
+        };
+        assert!(skew < TOLERANCE, "skew of {:?}", skew);
+    }
+}
+
+#[test]
+fn system_time_round_trips_through_std() {
+    let std = std::time::SystemTime::now();
+    let ours = unix_clock::SystemTime::try_from(std).unwrap();
+    assert_eq!(std::time::SystemTime::from(ours), std);
+}
+
+#[test]
+fn system_time_round_trips_before_the_epoch() {
+    for ours in [
+        unix_clock::SystemTime::new(-1, 999_999_999),
+        unix_clock::SystemTime::new(-1_700_000_000, 1),
+    ] {
+        let std = std::time::SystemTime::from(ours);
+        assert_eq!(
+            std::time::SystemTime::UNIX_EPOCH.duration_since(std).ok(),
+            unix_clock::SystemTime::UNIX_EPOCH.duration_since(ours).ok()
+        );
+        assert_eq!(unix_clock::SystemTime::try_from(std), Ok(ours));
+    }
+}
+
+#[test]
+fn instant_converts_to_std() {
+    for _ in 0..100 {
+        let ours = unix_clock::Instant::now();
+        let std = std::time::Instant::now();
+
+        let converted = ours.to_std();
+        let skew = if converted > std {
+            converted - std
+        } else {
+            std - converted
+        };
+        assert!(skew < TOLERANCE, "skew of {:?}", skew);
+    }
+
+    let later = unix_clock::Instant::now() + Duration::from_secs(60);
+    let std_later = later.to_std();
+    let ahead = std_later - std::time::Instant::now();
+    assert!(ahead > Duration::from_secs(59) && ahead <= Duration::from_secs(60));
+}
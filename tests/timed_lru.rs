@@ -0,0 +1,101 @@
+#![cfg(feature = "alloc")]
+
+use std::time::Duration;
+
+use unix_clock::{raw::Timespec, Instant, TimedLru};
+
+const TTL: Duration = Duration::from_secs(10);
+
+fn at(secs: u64) -> Instant {
+    Instant::from_timespec(Timespec::new(1_000, 0)) + Duration::from_secs(secs)
+}
+
+#[test]
+fn evicts_least_recently_used_when_full() {
+    let mut lru = TimedLru::new(3, TTL);
+    for (i, key) in ["a", "b", "c"].into_iter().enumerate() {
+        assert_eq!(lru.insert_at(at(0), key, i), None);
+    }
+    assert_eq!(lru.get_at(at(1), &"a"), Some(&0));
+
+    lru.insert_at(at(2), "d", 3);
+    assert_eq!(lru.len(), 3);
+    assert_eq!(lru.get_at(at(2), &"b"), None);
+
+    lru.insert_at(at(3), "e", 4);
+    assert_eq!(lru.get_at(at(3), &"c"), None);
+    for (key, value) in [("a", 0), ("d", 3), ("e", 4)] {
+        assert_eq!(lru.get_at(at(3), &key), Some(&value));
+    }
+}
+
+#[test]
+fn replacing_a_key_refreshes_it() {
+    let mut lru = TimedLru::new(2, TTL);
+    lru.insert_at(at(0), "a", 1);
+    lru.insert_at(at(0), "b", 2);
+    assert_eq!(lru.insert_at(at(5), "a", 10), Some(1));
+    assert_eq!(lru.len(), 2);
+
+    // "b" became the least recently used
+    lru.insert_at(at(6), "c", 3);
+    assert_eq!(lru.get_at(at(6), &"b"), None);
+    // and "a" lives 10s from its replacement
+    assert_eq!(lru.get_at(at(14), &"a"), Some(&10));
+    assert_eq!(lru.get_at(at(15), &"a"), None);
+}
+
+#[test]
+fn expires_entries_after_the_ttl() {
+    let mut lru = TimedLru::new(10, TTL);
+    lru.insert_at(at(0), "a", 1);
+    lru.insert_at(at(5), "b", 2);
+
+    assert_eq!(lru.get_at(at(9), &"a"), Some(&1));
+    assert_eq!(lru.get_at(at(10), &"a"), None);
+    assert_eq!(lru.len(), 1);
+    // a lookup doesn't extend the ttl
+    assert_eq!(lru.get_at(at(14), &"b"), Some(&2));
+    assert_eq!(lru.remove_at(at(15), &"b"), None);
+    assert!(lru.is_empty());
+}
+
+#[test]
+fn full_map_drops_expired_entries_before_live_ones() {
+    let mut lru = TimedLru::new(3, TTL);
+    lru.insert_at(at(0), "old", 0);
+    lru.insert_at(at(8), "a", 1);
+    lru.insert_at(at(9), "b", 2);
+    // "a" is the least recently used of the live entries
+    assert_eq!(lru.get_at(at(9), &"old"), Some(&0));
+
+    // "old" expired: it makes room, although it was used more recently
+    lru.insert_at(at(11), "c", 3);
+    assert_eq!(lru.len(), 3);
+    for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+        assert_eq!(lru.get_at(at(11), &key), Some(&value));
+    }
+
+    // nothing expired: the least recently used goes
+    lru.insert_at(at(12), "d", 4);
+    assert_eq!(lru.get_at(at(12), &"a"), None);
+}
+
+#[test]
+fn purges_expired_entries() {
+    let mut lru = TimedLru::new(10, TTL);
+    for i in 0..5 {
+        lru.insert_at(at(i), i, i);
+    }
+    lru.purge_expired_at(at(12));
+    assert_eq!(lru.len(), 2);
+    assert_eq!(lru.get_at(at(12), &3), Some(&3));
+    assert_eq!(lru.get_at(at(12), &4), Some(&4));
+}
+
+#[test]
+fn zero_capacity_stores_nothing() {
+    let mut lru = TimedLru::new(0, TTL);
+    assert_eq!(lru.insert_at(at(0), "a", 1), None);
+    assert!(lru.is_empty());
+}
@@ -0,0 +1,75 @@
+use std::{
+    panic::{self, catch_unwind},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use unix_clock::raw::Timespec;
+
+#[test]
+fn assign_operators() {
+    let mut t = Timespec::new(1, 500_000_000);
+    t += Duration::from_millis(700);
+    assert_eq!(t, Timespec::new(2, 200_000_000));
+    t -= Duration::from_millis(2_300);
+    assert_eq!(t, Timespec::new(-1, 900_000_000));
+}
+
+#[test]
+fn binary_operators() {
+    let t = Timespec::new(1, 500_000_000);
+    assert_eq!(
+        t + Duration::from_millis(700),
+        Timespec::new(2, 200_000_000)
+    );
+    assert_eq!(
+        t - Duration::from_millis(2_300),
+        Timespec::new(-1, 200_000_000)
+    );
+    assert_eq!(
+        t - Timespec::new(-1, 200_000_000),
+        Ok(Duration::from_millis(2_300))
+    );
+    assert_eq!(
+        Timespec::new(-1, 200_000_000) - t,
+        Err(Duration::from_millis(2_300))
+    );
+    assert_eq!(t - t, Ok(Duration::ZERO));
+}
+
+#[test]
+fn overflow_panics_at_caller() {
+    let location = Arc::new(Mutex::new(None));
+    let hook_location = Arc::clone(&location);
+    let prev = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        *hook_location.lock().unwrap() = info.location().map(|l| (l.file().to_owned(), l.line()));
+    }));
+
+    let add_line = line!() + 3;
+    let add = catch_unwind(|| {
+        let mut t = Timespec::MAX;
+        t += Duration::from_nanos(1);
+    });
+    let add_location = location.lock().unwrap().take();
+
+    let sub_line = line!() + 3;
+    let sub = catch_unwind(|| {
+        let mut t = Timespec::MIN;
+        t -= Duration::from_nanos(1);
+    });
+    let sub_location = location.lock().unwrap().take();
+
+    let binary_line = line!() + 1;
+    let binary = catch_unwind(|| Timespec::MAX + Duration::from_nanos(1));
+    let binary_location = location.lock().unwrap().take();
+
+    panic::set_hook(prev);
+
+    assert!(add.is_err());
+    assert_eq!(add_location, Some((file!().to_owned(), add_line)));
+    assert!(sub.is_err());
+    assert_eq!(sub_location, Some((file!().to_owned(), sub_line)));
+    assert!(binary.is_err());
+    assert_eq!(binary_location, Some((file!().to_owned(), binary_line)));
+}
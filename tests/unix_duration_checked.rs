@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use unix_clock::{OutOfRange, SystemTime};
+
+const MIN: SystemTime = SystemTime::new(946_684_800, 0);
+const MAX: SystemTime = SystemTime::new(4_102_444_800, 0);
+
+fn checked(secs: u64, nanos: u32) -> Result<SystemTime, OutOfRange> {
+    SystemTime::from_unix_duration_checked(Duration::new(secs, nanos), MIN, MAX)
+}
+
+#[test]
+fn accepts_the_window_boundaries() {
+    assert_eq!(checked(946_684_800, 0), Ok(MIN));
+    assert_eq!(checked(4_102_444_800, 0), Ok(MAX));
+}
+
+#[test]
+fn accepts_values_inside() {
+    assert_eq!(
+        checked(1_700_000_000, 123),
+        Ok(SystemTime::new(1_700_000_000, 123))
+    );
+    assert_eq!(checked(946_684_800, 1), Ok(SystemTime::new(946_684_800, 1)));
+}
+
+#[test]
+fn rejects_values_outside() {
+    assert_eq!(checked(0, 0), Err(OutOfRange { after_max: false }));
+    assert_eq!(
+        checked(946_684_799, 999_999_999),
+        Err(OutOfRange { after_max: false })
+    );
+    assert_eq!(
+        checked(4_102_444_800, 1),
+        Err(OutOfRange { after_max: true })
+    );
+    assert_eq!(
+        checked(u64::MAX, 999_999_999),
+        Err(OutOfRange { after_max: true })
+    );
+}
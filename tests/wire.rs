@@ -0,0 +1,52 @@
+use unix_clock::raw::{Timespec, WIRE_SIZE};
+
+#[test]
+fn encodes_little_endian() {
+    let cases: &[(i64, u32, [u8; WIRE_SIZE])] = &[
+        (0, 0, [0; WIRE_SIZE]),
+        (
+            1_700_000_000,
+            123_456_789,
+            [0x00, 0xf1, 0x53, 0x65, 0, 0, 0, 0, 0x15, 0xcd, 0x5b, 0x07],
+        ),
+        (
+            -1,
+            999_999_999,
+            [
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x9a, 0x3b,
+            ],
+        ),
+        (i64::MIN, 0, [0, 0, 0, 0, 0, 0, 0, 0x80, 0, 0, 0, 0]),
+        (
+            i64::MAX,
+            1,
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 1, 0, 0, 0],
+        ),
+    ];
+
+    for &(secs, nsecs, bytes) in cases {
+        let t = Timespec::new(secs, nsecs);
+        assert_eq!(t.to_wire(), bytes, "encoding {:?}", t);
+        assert_eq!(Timespec::from_wire(&bytes), Some(t), "decoding {:?}", t);
+    }
+}
+
+#[test]
+fn decodes_bytes_from_big_endian_host() {
+    // Produced by `Timespec::new(-86_400, 500_000_000).to_wire()` on a
+    // big-endian host: the format doesn't depend on the host.
+    let bytes = [
+        0x80, 0xae, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x65, 0xcd, 0x1d,
+    ];
+    assert_eq!(
+        Timespec::from_wire(&bytes),
+        Some(Timespec::new(-86_400, 500_000_000))
+    );
+}
+
+#[test]
+fn rejects_unnormalized_nanoseconds() {
+    let mut bytes = Timespec::zero().to_wire();
+    bytes[8..].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+    assert_eq!(Timespec::from_wire(&bytes), None);
+}